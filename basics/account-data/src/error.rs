@@ -0,0 +1,9 @@
+use pinocchio_helper::program_error;
+
+program_error! {
+    /// Errors returned by the account-data program
+    pub enum AccountDataError {
+        /// The update mask is empty or selects unknown fields
+        InvalidFieldMask = 6001,
+    }
+}
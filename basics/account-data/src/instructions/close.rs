@@ -0,0 +1,23 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_helper::lamports::close_account;
+use crate::state::CloseAddressInfoAccounts;
+
+pub struct Close<'info> {
+    pub accounts: CloseAddressInfoAccounts<'info>,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for Close<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = CloseAddressInfoAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> Close<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        close_account(self.accounts.address_info, self.accounts.destination)
+    }
+}
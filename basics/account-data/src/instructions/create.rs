@@ -1,9 +1,5 @@
-use pinocchio::{
-    account_info::AccountInfo,
-    program_error::ProgramError,
-    sysvars::{rent::Rent, Sysvar},
-    ProgramResult,
-};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_helper::cpi::create_account;
 use crate::state::{AddressInfo, CreateAddressInfoAccounts, CreateAddressInfoInstructionData};
 
 pub struct Create<'info> {
@@ -11,14 +7,13 @@ pub struct Create<'info> {
     pub instruction_data: CreateAddressInfoInstructionData,
 }
 
-impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Create<'info> {
+impl<'info> TryFrom<(&'info [AccountInfo], CreateAddressInfoInstructionData)> for Create<'info> {
     type Error = ProgramError;
 
     fn try_from(
-        (accounts, data): (&'info [AccountInfo], &'info [u8]),
+        (accounts, instruction_data): (&'info [AccountInfo], CreateAddressInfoInstructionData),
     ) -> Result<Self, Self::Error> {
         let accounts = CreateAddressInfoAccounts::try_from(accounts)?;
-        let instruction_data = CreateAddressInfoInstructionData::try_from(data)?;
 
         Ok(Self {
             accounts,
@@ -29,14 +24,12 @@ impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Create<'info> {
 
 impl<'info> Create<'info> {
     pub fn handler(&mut self) -> ProgramResult {
-        pinocchio_system::instructions::CreateAccount {
-            from: self.accounts.payer,
-            to: self.accounts.address_info,
-            space: AddressInfo::LEN as u64,
-            lamports: Rent::get()?.minimum_balance(AddressInfo::LEN),
-            owner: &crate::ID,
-        }
-        .invoke()?;
+        create_account(
+            self.accounts.payer,
+            self.accounts.address_info,
+            AddressInfo::LEN,
+            &crate::ID,
+        )?;
 
         let address_info_state = unsafe {
             bytemuck::try_from_bytes_mut::<AddressInfo>(
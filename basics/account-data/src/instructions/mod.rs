@@ -1,8 +1,14 @@
+pub mod close;
 pub mod create;
+pub mod update;
+pub use close::*;
 pub use create::*;
+pub use update::*;
 use pinocchio::program_error::ProgramError;
 use shank::ShankInstruction;
 
+use crate::state::{CreateAddressInfoInstructionData, UpdateAddressInfoInstructionData};
+
 #[derive(ShankInstruction)]
 #[repr(u8)]
 pub enum Instruction {
@@ -26,15 +32,53 @@ pub enum Instruction {
         name = "system_program",
         desc = "System Program for account creation"
     )]
-    Create,
+    Create(CreateAddressInfoInstructionData),
+
+    /// Overwrite only the fields selected by the leading bitmask
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "address_info",
+        desc = "The address info account to patch"
+    )]
+    UpdateAddressInfo(UpdateAddressInfoInstructionData),
+
+    /// Close the address info account and reclaim its rent
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "address_info",
+        desc = "The address info account to close"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "destination",
+        desc = "Receives the reclaimed lamports"
+    )]
+    CloseAddressInfo,
 }
 
-impl TryFrom<&u8> for Instruction {
+/// Splits off the discriminator and parses the payload into the variant, so
+/// the arguments Shank reads from each variant are the ones handlers receive.
+impl TryFrom<&[u8]> for Instruction {
     type Error = ProgramError;
 
-    fn try_from(value: &u8) -> Result<Self, Self::Error> {
-        match *value {
-            0 => Ok(Instruction::Create),
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let (discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            0 => Ok(Instruction::Create(
+                CreateAddressInfoInstructionData::try_from(data)?,
+            )),
+            1 => Ok(Instruction::UpdateAddressInfo(
+                UpdateAddressInfoInstructionData::try_from(data)?,
+            )),
+            2 if data.is_empty() => Ok(Instruction::CloseAddressInfo),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
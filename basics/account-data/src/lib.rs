@@ -4,6 +4,7 @@
 use pinocchio_pubkey::declare_id;
 
 pub mod constants;
+pub mod error;
 pub mod instructions;
 pub mod processor;
 pub mod state;
@@ -2,7 +2,7 @@ use pinocchio::{
     account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
 };
 
-use crate::instructions::{Create, Instruction};
+use crate::instructions::{Close, Create, Instruction, Update};
 use pinocchio_log::log;
 
 #[inline(always)]
@@ -15,14 +15,18 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let (discriminator, data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
-    match Instruction::try_from(discriminator)? {
-        Instruction::Create => {
+    match Instruction::try_from(instruction_data)? {
+        Instruction::Create(data) => {
             log!("Instruction::Create");
             Create::try_from((accounts, data))?.handler()
         }
+        Instruction::UpdateAddressInfo(data) => {
+            log!("Instruction::UpdateAddressInfo");
+            Update::try_from((accounts, data))?.handler()
+        }
+        Instruction::CloseAddressInfo => {
+            log!("Instruction::CloseAddressInfo");
+            Close::try_from(accounts)?.handler()
+        }
     }
 }
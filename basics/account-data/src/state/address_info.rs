@@ -1,10 +1,29 @@
 use bytemuck::{Pod, Zeroable};
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::program_error::ProgramError;
+use pinocchio_helper::instruction_accounts;
 use shank::ShankAccount;
 
-pub struct CreateAddressInfoAccounts<'info> {
-    pub payer: &'info AccountInfo,
-    pub address_info: &'info AccountInfo,
+use crate::error::AccountDataError;
+
+instruction_accounts! {
+    pub struct CreateAddressInfoAccounts {
+        [signer, writable] payer,
+        [signer, writable, empty] address_info,
+        system_program,
+    }
+}
+
+instruction_accounts! {
+    pub struct UpdateAddressInfoAccounts {
+        [signer, writable, program_owned, data] address_info,
+    }
+}
+
+instruction_accounts! {
+    pub struct CloseAddressInfoAccounts {
+        [signer, writable, program_owned] address_info,
+        [writable] destination,
+    }
 }
 
 /// Address information account containing personal address details
@@ -25,6 +44,14 @@ pub struct AddressInfo {
 impl AddressInfo {
     pub const LEN: usize = core::mem::size_of::<AddressInfo>();
 
+    /// Field bits used by `UpdateAddressInfo`
+    pub const FIELD_NAME: u8 = 1 << 0;
+    pub const FIELD_HOUSE_NUMBER: u8 = 1 << 1;
+    pub const FIELD_STREET: u8 = 1 << 2;
+    pub const FIELD_CITY: u8 = 1 << 3;
+    pub const FIELD_ALL: u8 =
+        Self::FIELD_NAME | Self::FIELD_HOUSE_NUMBER | Self::FIELD_STREET | Self::FIELD_CITY;
+
     pub fn set_inner(&mut self, data: Self) -> Self {
         self.name = data.name;
         self.house_number = data.house_number;
@@ -34,37 +61,47 @@ impl AddressInfo {
     }
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for CreateAddressInfoAccounts<'info> {
-    type Error = ProgramError;
+/// Instruction data for creating an address info account
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CreateAddressInfoInstructionData {
+    /// Full name (up to 50 bytes, UTF-8 encoded)
+    pub name: [u8; 50],
+    /// House number (0-255)
+    pub house_number: u8,
+    /// Street name (up to 50 bytes, UTF-8 encoded)
+    pub street: [u8; 50],
+    /// City name (up to 50 bytes, UTF-8 encoded)
+    pub city: [u8; 50],
+}
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [payer, address_info, _] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
+impl CreateAddressInfoInstructionData {
+    pub const LEN: usize = core::mem::size_of::<CreateAddressInfoInstructionData>();
+}
 
-        if !payer.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+impl<'info> TryFrom<&'info [u8]> for CreateAddressInfoInstructionData {
+    type Error = ProgramError;
 
-        if !address_info.is_signer() {
-            return Err(ProgramError::InvalidAccountData);
-        }
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
 
-        if address_info.data_len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
-        }
-        Ok(Self {
-            payer,
-            address_info,
-        })
+        Ok(*result)
     }
 }
 
-/// Instruction data for creating an address info account
+/// Instruction data for patching an address info account.
+///
+/// Only the fields selected by the `fields` mask are copied into the account;
+/// the values of unselected fields are ignored and left untouched on chain, so
+/// a client can change the street without reading back and resending the rest.
 #[derive(shank::ShankType)]
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
-pub struct CreateAddressInfoInstructionData {
+pub struct UpdateAddressInfoInstructionData {
+    /// `AddressInfo::FIELD_*` bits selecting the fields to overwrite
+    pub fields: u8,
     /// Full name (up to 50 bytes, UTF-8 encoded)
     pub name: [u8; 50],
     /// House number (0-255)
@@ -75,17 +112,39 @@ pub struct CreateAddressInfoInstructionData {
     pub city: [u8; 50],
 }
 
-impl CreateAddressInfoInstructionData {
-    pub const LEN: usize = core::mem::size_of::<CreateAddressInfoInstructionData>();
+impl UpdateAddressInfoInstructionData {
+    pub const LEN: usize = core::mem::size_of::<UpdateAddressInfoInstructionData>();
+
+    /// Copy the selected fields into `state`, leaving every other byte as is
+    pub fn apply(&self, state: &mut AddressInfo) {
+        if self.fields & AddressInfo::FIELD_NAME != 0 {
+            state.name = self.name;
+        }
+        if self.fields & AddressInfo::FIELD_HOUSE_NUMBER != 0 {
+            state.house_number = self.house_number;
+        }
+        if self.fields & AddressInfo::FIELD_STREET != 0 {
+            state.street = self.street;
+        }
+        if self.fields & AddressInfo::FIELD_CITY != 0 {
+            state.city = self.city;
+        }
+    }
 }
 
-impl<'info> TryFrom<&'info [u8]> for CreateAddressInfoInstructionData {
+impl<'info> TryFrom<&'info [u8]> for UpdateAddressInfoInstructionData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        let result = bytemuck::try_from_bytes::<Self>(&data)
+        let result = bytemuck::try_from_bytes::<Self>(data)
             .map_err(|_| ProgramError::InvalidInstructionData)?;
 
+        // An empty mask is a no-op and unknown bits are most likely a client
+        // built against a newer layout, so reject both.
+        if result.fields == 0 || result.fields & !AddressInfo::FIELD_ALL != 0 {
+            return Err(AccountDataError::InvalidFieldMask.into());
+        }
+
         Ok(*result)
     }
 }
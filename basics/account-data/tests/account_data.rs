@@ -1,26 +1,39 @@
 #[cfg(test)]
 mod tests {
     use account_data::{
-        state::{AddressInfo, CreateAddressInfoInstructionData},
+        error::AccountDataError,
+        state::{AddressInfo, CreateAddressInfoInstructionData, UpdateAddressInfoInstructionData},
         ID,
     };
-    use mollusk_svm::{
-        result::{Check, ProgramResult},
-        Mollusk,
-    };
+    use mollusk_svm::result::{Check, ProgramResult};
     use pinocchio_helper::create_padded_array;
     use solana_sdk::{
         account::AccountSharedData,
         instruction::{AccountMeta, Instruction},
         native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError,
         pubkey::Pubkey,
     };
 
     pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
 
+    fn existing_address_info() -> (AddressInfo, AccountSharedData) {
+        let state = AddressInfo {
+            name: create_padded_array(b"Solana", 50),
+            house_number: 136,
+            street: create_padded_array(b"Solana Street", 50),
+            city: create_padded_array(b"Pinocchio City", 50),
+        };
+
+        let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, AddressInfo::LEN, &PROGRAM_ID);
+        account.set_data_from_slice(bytemuck::bytes_of(&state));
+
+        (state, account)
+    }
+
     #[test]
     fn test_create_account_data() {
-        let mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/account_data");
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "account_data");
 
         let (system_program, system_account) =
             mollusk_svm::program::keyed_account_for_system_program();
@@ -78,4 +91,156 @@ mod tests {
 
         assert!(result.program_result == ProgramResult::Success);
     }
+
+    #[test]
+    fn test_create_requires_address_info_signature() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "account_data");
+
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let address_info_pubkey = Pubkey::new_unique();
+
+        let ix_data = CreateAddressInfoInstructionData {
+            name: create_padded_array(b"Solana", 50),
+            house_number: 136,
+            street: create_padded_array(b"Solana Street", 50),
+            city: create_padded_array(b"Pinocchio City", 50),
+        };
+        let data = [vec![0], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(owner, true),
+                AccountMeta::new(address_info_pubkey, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    owner,
+                    AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (
+                    address_info_pubkey,
+                    AccountSharedData::new(0, 0, &system_program).into(),
+                ),
+                (system_program, system_account),
+            ],
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        );
+    }
+
+    #[test]
+    fn test_update_patches_selected_fields_only() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "account_data");
+
+        let address_info_pubkey = Pubkey::new_unique();
+        let (mut expected, address_info_account) = existing_address_info();
+
+        let new_street: [u8; 50] = create_padded_array(b"Anchor Avenue", 50);
+        // Name and city are ignored because the mask doesn't select them
+        let ix_data = UpdateAddressInfoInstructionData {
+            fields: AddressInfo::FIELD_HOUSE_NUMBER | AddressInfo::FIELD_STREET,
+            name: create_padded_array(b"Ignored", 50),
+            house_number: 42,
+            street: new_street,
+            city: [0; 50],
+        };
+        let data = [vec![1], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![AccountMeta::new(address_info_pubkey, true)],
+        );
+
+        expected.house_number = 42;
+        expected.street = new_street;
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[(address_info_pubkey, address_info_account.into())],
+            &[
+                Check::success(),
+                Check::account(&address_info_pubkey)
+                    .data(bytemuck::bytes_of(&expected))
+                    .build(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_update_rejects_unknown_field_bits() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "account_data");
+
+        let address_info_pubkey = Pubkey::new_unique();
+        let (state, address_info_account) = existing_address_info();
+
+        let ix_data = UpdateAddressInfoInstructionData {
+            fields: AddressInfo::FIELD_NAME | 0x80,
+            name: create_padded_array(b"Anatoly", 50),
+            house_number: state.house_number,
+            street: state.street,
+            city: state.city,
+        };
+        let data = [vec![1], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![AccountMeta::new(address_info_pubkey, true)],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[(address_info_pubkey, address_info_account.into())],
+            &[Check::err(ProgramError::Custom(
+                AccountDataError::InvalidFieldMask as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_close_returns_rent_to_destination() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "account_data");
+
+        let address_info_pubkey = Pubkey::new_unique();
+        let (_, address_info_account) = existing_address_info();
+
+        let destination = Pubkey::new_unique();
+        let destination_account =
+            AccountSharedData::new(LAMPORTS_PER_SOL, 0, &solana_sdk::system_program::ID);
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[2],
+            vec![
+                AccountMeta::new(address_info_pubkey, true),
+                AccountMeta::new(destination, false),
+            ],
+        );
+
+        let result = mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (address_info_pubkey, address_info_account.into()),
+                (destination, destination_account.into()),
+            ],
+            &[Check::success()],
+        );
+
+        let closed = result.get_account(&address_info_pubkey).unwrap();
+        assert_eq!(closed.lamports, 0);
+        assert!(closed.data.is_empty());
+
+        let destination_account = result.get_account(&destination).unwrap();
+        assert_eq!(destination_account.lamports, 2 * LAMPORTS_PER_SOL);
+    }
 }
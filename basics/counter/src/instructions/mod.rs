@@ -12,25 +12,40 @@ pub enum CounterInstruction {
     #[account(0, writable, signer, name = "maker", desc = "The payer of the counter")]
     #[account(1, writable, name = "counter", desc = "The counter account")]
     #[account(2, name = "system_program", desc = "The system program")]
-    Create,
+    Create(CreateCounterInstructionData),
 
+    /// Add `MutateCounterOptions::step`, subject to the guards enabled in its
+    /// `flags`. Sending no options at all adds one.
     #[account(0, writable, signer, name = "authority", desc = "Counter authority")]
     #[account(1, writable, name = "counter", desc = "The counter account")]
-    Increase,
+    Increase(MutateCounterOptions),
 
+    /// Subtract `MutateCounterOptions::step`, same as `Increase`
     #[account(0, writable, signer, name = "authority", desc = "Counter authority")]
     #[account(1, writable, name = "counter", desc = "The counter account")]
-    Decrease,
+    Decrease(MutateCounterOptions),
 }
 
-impl TryFrom<&u8> for CounterInstruction {
+/// Splits off the discriminator and parses the payload into the variant, so
+/// the arguments Shank reads from each variant are the ones handlers receive.
+impl TryFrom<&[u8]> for CounterInstruction {
     type Error = ProgramError;
 
-    fn try_from(value: &u8) -> Result<Self, Self::Error> {
-        match *value {
-            0 => Ok(CounterInstruction::Create),
-            1 => Ok(CounterInstruction::Increase),
-            2 => Ok(CounterInstruction::Decrease),
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let (discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            0 => Ok(CounterInstruction::Create(
+                CreateCounterInstructionData::try_from(data)?,
+            )),
+            1 => Ok(CounterInstruction::Increase(
+                MutateCounterOptions::try_from(data)?,
+            )),
+            2 => Ok(CounterInstruction::Decrease(
+                MutateCounterOptions::try_from(data)?,
+            )),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -1,4 +1,12 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey, ProgramResult};
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_helper::{checks::require_key, instruction_accounts, le::U64LE};
 
 use crate::{
     constants::COUNTER_SEED,
@@ -6,62 +14,108 @@ use crate::{
     state::{Counter, MutationType},
 };
 
-pub struct MutateCounterInstructionData {
-    pub mutation: MutationType,
+/// Optional Increase/Decrease payload. Without it the counter moves by one.
+///
+/// `expected_count` turns the mutation into a compare-and-set: it only applies
+/// if nobody changed the counter since the client read it. `expiry_slot` drops
+/// a transaction that landed too late to still reflect the user's intent.
+/// Each guard is only enforced when its bit is set in `flags`; other bits and a
+/// zero `step` are rejected.
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct MutateCounterOptions {
+    #[idl_type("u64")]
+    pub step: U64LE,
+    #[idl_type("u64")]
+    pub expected_count: U64LE,
+    #[idl_type("u64")]
+    pub expiry_slot: U64LE,
+    pub flags: u8,
+}
+
+impl MutateCounterOptions {
+    pub const LEN: usize = core::mem::size_of::<MutateCounterOptions>();
+
+    pub const FLAG_EXPECTED_COUNT: u8 = 1 << 0;
+    pub const FLAG_EXPIRY_SLOT: u8 = 1 << 1;
+    pub const KNOWN_FLAGS: u8 = Self::FLAG_EXPECTED_COUNT | Self::FLAG_EXPIRY_SLOT;
 }
 
-impl MutateCounterInstructionData {
-    pub const LEN: usize = core::mem::size_of::<MutateCounterInstructionData>();
+/// Leaving the options out entirely moves the counter by one with no guards.
+impl Default for MutateCounterOptions {
+    fn default() -> Self {
+        Self {
+            step: U64LE::new(1),
+            ..Self::zeroed()
+        }
+    }
 }
 
-impl<'info> TryFrom<&'info [u8]> for MutateCounterInstructionData {
+impl<'info> TryFrom<&'info [u8]> for MutateCounterOptions {
     type Error = ProgramError;
+
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        let mutation =
-            MutationType::try_from(data).map_err(|_| ProgramError::InvalidInstructionData)?;
-        Ok(Self { mutation })
+        if data.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
     }
 }
 
-pub struct MutateCounterIxsAccounts<'info> {
-    pub maker: &'info AccountInfo,
-    pub counter: &'info AccountInfo,
+pub struct MutateCounterInstructionData {
+    pub step: u64,
+    pub expected_count: Option<u64>,
+    pub expiry_slot: Option<u64>,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for MutateCounterIxsAccounts<'info> {
+impl TryFrom<MutateCounterOptions> for MutateCounterInstructionData {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [maker, counter, _] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-
-        if !maker.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if !counter.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
+    fn try_from(options: MutateCounterOptions) -> Result<Self, Self::Error> {
+        if options.flags & !MutateCounterOptions::KNOWN_FLAGS != 0 {
+            return Err(CounterError::UnknownFlags.into());
         }
-
-        if !counter.is_owned_by(&crate::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+        if options.step.get() == 0 {
+            return Err(CounterError::ZeroStep.into());
         }
+        let flag = |bit: u8, value: U64LE| (options.flags & bit != 0).then(|| value.get());
+
+        Ok(Self {
+            step: options.step.get(),
+            expected_count: flag(
+                MutateCounterOptions::FLAG_EXPECTED_COUNT,
+                options.expected_count,
+            ),
+            expiry_slot: flag(MutateCounterOptions::FLAG_EXPIRY_SLOT, options.expiry_slot),
+        })
+    }
+}
 
-        Ok(Self { maker, counter })
+instruction_accounts! {
+    pub struct MutateCounterIxsAccounts {
+        [signer, writable] authority,
+        [writable, program_owned] counter,
     }
 }
 
 pub struct Mutate<'info> {
     pub accounts: MutateCounterIxsAccounts<'info>,
+    pub data: MutateCounterInstructionData,
 }
 
-impl<'info> TryFrom<&'info [AccountInfo]> for Mutate<'info> {
+impl<'info> TryFrom<(&'info [AccountInfo], MutateCounterOptions)> for Mutate<'info> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+    fn try_from(
+        (accounts, options): (&'info [AccountInfo], MutateCounterOptions),
+    ) -> Result<Self, Self::Error> {
         let accounts = MutateCounterIxsAccounts::try_from(accounts)?;
-        Ok(Self { accounts })
+        let data = MutateCounterInstructionData::try_from(options)?;
+        Ok(Self { accounts, data })
     }
 }
 
@@ -77,8 +131,17 @@ impl<'info> Mutate<'info> {
         let seeds = &[COUNTER_SEED];
         let (counter_pubkey, _) = pubkey::find_program_address(seeds, &crate::ID);
 
-        if self.accounts.counter.key().ne(&counter_pubkey) {
-            return Err(ProgramError::InvalidAccountData);
+        require_key(1, self.accounts.counter, &counter_pubkey)?;
+
+        if let Some(expiry_slot) = self.data.expiry_slot {
+            if Clock::get()?.slot > expiry_slot {
+                return Err(CounterError::Expired.into());
+            }
+        }
+        if let Some(expected_count) = self.data.expected_count {
+            if counter.count.get() != expected_count {
+                return Err(CounterError::StaleCount.into());
+            }
         }
 
         match action {
@@ -90,18 +153,18 @@ impl<'info> Mutate<'info> {
     }
 
     fn increment(&mut self, counter: &mut Counter) -> ProgramResult {
-        let mutated_state = u64::from_le_bytes(counter.count)
-            .checked_add(1)
+        counter.count = counter
+            .count
+            .checked_add(self.data.step)
             .ok_or(CounterError::Overflow)?;
-        counter.count = mutated_state.to_le_bytes();
         Ok(())
     }
 
     fn decrement(&mut self, counter: &mut Counter) -> ProgramResult {
-        let mutated_state = u64::from_le_bytes(counter.count)
-            .checked_sub(1)
+        counter.count = counter
+            .count
+            .checked_sub(self.data.step)
             .ok_or(CounterError::Overflow)?;
-        counter.count = mutated_state.to_le_bytes();
         Ok(())
     }
 }
@@ -16,22 +16,18 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let (discriminator, data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
-    match CounterInstruction::try_from(discriminator)? {
-        CounterInstruction::Create => {
+    match CounterInstruction::try_from(instruction_data)? {
+        CounterInstruction::Create(data) => {
             log!("CounterInstruction::Create");
             Create::try_from((accounts, data))?.handler()
         }
-        CounterInstruction::Increase => {
+        CounterInstruction::Increase(options) => {
             log!("CounterInstruction::Increase");
-            Mutate::try_from(accounts)?.handler(MutationType::INCREASE)
+            Mutate::try_from((accounts, options))?.handler(MutationType::INCREASE)
         }
-        CounterInstruction::Decrease => {
+        CounterInstruction::Decrease(options) => {
             log!("CounterInstruction::Decrease");
-            Mutate::try_from(accounts)?.handler(MutationType::DECREASE)
+            Mutate::try_from((accounts, options))?.handler(MutationType::DECREASE)
         }
     }
 }
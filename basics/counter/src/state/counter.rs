@@ -1,12 +1,14 @@
 use bytemuck::{Pod, Zeroable};
 use pinocchio::program_error::ProgramError;
+use pinocchio_helper::le::U64LE;
 use shank::ShankAccount;
 
 #[derive(ShankAccount)]
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct Counter {
-    pub count: [u8; 8],
+    #[idl_type("u64")]
+    pub count: U64LE,
 }
 
 impl Counter {
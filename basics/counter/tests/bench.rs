@@ -0,0 +1,171 @@
+//! Compute-unit comparison between this counter and an equivalent Anchor
+//! program loaded from `tests/fixtures/anchor_counter.so` (see the README in
+//! that directory for its source). Without the fixture only the Pinocchio
+//! column is filled in.
+//!
+//! Both programs get byte-for-byte the same account lists, so the table only
+//! measures the programs. The test runs with the rest of the suite and writes
+//! the table under the target dir; `npm run bench:counter` sets
+//! `MOLLUSK_BENCH_DIR=benches` to refresh the committed
+//! `benches/counter_vs_anchor.md` instead.
+
+#[cfg(test)]
+mod tests {
+    use counter::{constants::COUNTER_SEED, instructions::CreateCounterInstructionData, ID};
+    use mollusk_svm::{result::ProgramResult, Mollusk};
+    use pinocchio_helper::le::U64LE;
+    use solana_sdk::{
+        account::Account,
+        hash::hashv,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey,
+        pubkey::Pubkey,
+    };
+    use test_support::bench::{fixture_path, Comparison};
+
+    pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
+
+    /// `declare_id!` of the Anchor fixture; Anchor rejects any other program id
+    const ANCHOR_PROGRAM_ID: Pubkey = pubkey!("Cd3qco6kiJ5xza2J2P733ZkCzKBcqw637Bds2Ni3WJUb");
+
+    const STEPS: [&str; 3] = ["Create", "Increase", "Decrease"];
+
+    /// Run `instructions` in order, carrying accounts between them, and return
+    /// the compute units each one consumed.
+    fn measure(
+        mollusk: &Mollusk,
+        instructions: &[Instruction],
+        mut accounts: Vec<(Pubkey, Account)>,
+    ) -> Vec<u64> {
+        instructions
+            .iter()
+            .map(|instruction| {
+                let result = mollusk.process_instruction(instruction, &accounts);
+                assert!(
+                    result.program_result == ProgramResult::Success,
+                    "{:?}",
+                    result.program_result
+                );
+                accounts = result.resulting_accounts;
+                result.compute_units_consumed
+            })
+            .collect()
+    }
+
+    /// Accounts shared by both programs: the payer/authority, then the counter
+    /// PDA, then the system program for `Create` only.
+    fn account_metas(
+        owner: Pubkey,
+        counter: Pubkey,
+        with_system_program: bool,
+    ) -> Vec<AccountMeta> {
+        let mut metas = vec![
+            AccountMeta::new(owner, true),
+            AccountMeta::new(counter, false),
+        ];
+        if with_system_program {
+            let (system_program, _) = mollusk_svm::program::keyed_account_for_system_program();
+            metas.push(AccountMeta::new_readonly(system_program, false));
+        }
+        metas
+    }
+
+    fn initial_accounts(counter: Pubkey) -> Vec<(Pubkey, Account)> {
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        vec![
+            (
+                Pubkey::new_from_array([0x02; 32]),
+                Account::new(LAMPORTS_PER_SOL, 0, &system_program),
+            ),
+            (counter, Account::new(0, 0, &system_program)),
+            (system_program, system_account),
+        ]
+    }
+
+    fn pinocchio_counter() -> Vec<u64> {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter, bump) = Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let create_data = CreateCounterInstructionData {
+            initial_value: U64LE::new(100),
+            bump,
+        };
+        let ix = |data: Vec<u8>, with_system_program: bool| {
+            Instruction::new_with_bytes(
+                PROGRAM_ID,
+                &data,
+                account_metas(owner, counter, with_system_program),
+            )
+        };
+
+        measure(
+            &mollusk,
+            &[
+                ix(
+                    [vec![0], bytemuck::bytes_of(&create_data).to_vec()].concat(),
+                    true,
+                ),
+                ix(vec![1], false),
+                ix(vec![2], false),
+            ],
+            initial_accounts(counter),
+        )
+    }
+
+    fn anchor_counter(path: &str) -> Vec<u64> {
+        let mollusk = Mollusk::new(&ANCHOR_PROGRAM_ID, path);
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter, _) = Pubkey::find_program_address(&[COUNTER_SEED], &ANCHOR_PROGRAM_ID);
+
+        let discriminator =
+            |name: &str| hashv(&[format!("global:{name}").as_bytes()]).to_bytes()[..8].to_vec();
+        let ix = |data: Vec<u8>, with_system_program: bool| {
+            Instruction::new_with_bytes(
+                ANCHOR_PROGRAM_ID,
+                &data,
+                account_metas(owner, counter, with_system_program),
+            )
+        };
+
+        measure(
+            &mollusk,
+            &[
+                ix(
+                    [discriminator("create"), 100u64.to_le_bytes().to_vec()].concat(),
+                    true,
+                ),
+                ix(discriminator("increase"), false),
+                ix(discriminator("decrease"), false),
+            ],
+            initial_accounts(counter),
+        )
+    }
+
+    #[test]
+    fn bench_counter_vs_anchor() {
+        let pinocchio = pinocchio_counter();
+        let anchor = fixture_path("anchor_counter").map(|path| anchor_counter(&path));
+
+        let mut comparison =
+            Comparison::new("Counter: Pinocchio vs Anchor", &["Pinocchio", "Anchor"]);
+        for (i, step) in STEPS.iter().enumerate() {
+            comparison.row(step, &[Some(pinocchio[i]), anchor.as_ref().map(|cu| cu[i])]);
+        }
+        if anchor.is_none() {
+            comparison.note(
+                "Anchor column empty: build `tests/fixtures/anchor_counter.so` as described in \
+                 `tests/fixtures/README.md` and rerun.",
+            );
+        }
+
+        let path = comparison.write("counter_vs_anchor").unwrap();
+        println!(
+            "{}\nwritten to {}",
+            comparison.to_markdown(),
+            path.display()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use counter::{
+        constants::COUNTER_SEED, error::CounterError, instructions::MutateCounterOptions,
+        state::Counter, ID,
+    };
+    use counter_client::instructions::{CreateBuilder, IncreaseBuilder};
+    use mollusk_svm::{result::Check, Mollusk};
+    use pinocchio_helper::le::U64LE;
+    use solana_sdk::{
+        account::AccountSharedData, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError, pubkey::Pubkey,
+    };
+
+    pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
+
+    /// Process an instruction built by `counter-client` against a counter at
+    /// `count`, so a client that drifts from the program fails here.
+    fn process_with_counter(
+        mollusk: &Mollusk,
+        instruction: &Instruction,
+        count: u64,
+        checks: &[Check],
+    ) {
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, _) = Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let mut counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(Counter::LEN),
+            Counter::LEN,
+            &PROGRAM_ID,
+        );
+        counter_account.set_data_from_slice(bytemuck::bytes_of(&Counter {
+            count: U64LE::new(count),
+        }));
+
+        mollusk.process_and_validate_instruction(
+            instruction,
+            &[
+                (
+                    owner,
+                    AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (counter_pubkey, counter_account.into()),
+                (system_program, system_account),
+            ],
+            checks,
+        );
+    }
+
+    #[test]
+    fn test_client_program_id_matches() {
+        assert_eq!(counter_client::ID, PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_client_create() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, bump) = Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let instruction = CreateBuilder::new()
+            .maker(owner)
+            .counter(counter_pubkey)
+            .initial_value(100)
+            .bump(bump)
+            .instruction();
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    owner,
+                    AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (
+                    counter_pubkey,
+                    AccountSharedData::new(0, 0, &system_program).into(),
+                ),
+                (system_program, system_account),
+            ],
+            &[
+                Check::success(),
+                Check::account(&counter_pubkey).owner(&PROGRAM_ID).build(),
+                Check::account(&counter_pubkey)
+                    .data(bytemuck::bytes_of(&Counter {
+                        count: U64LE::new(100),
+                    }))
+                    .build(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_client_increase_with_options() {
+        let mut mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        mollusk.warp_to_slot(10);
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, _) = Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let instruction = IncreaseBuilder::new()
+            .authority(owner)
+            .counter(counter_pubkey)
+            .step(5)
+            .expected_count(100)
+            .expiry_slot(10)
+            .flags(
+                MutateCounterOptions::FLAG_EXPECTED_COUNT | MutateCounterOptions::FLAG_EXPIRY_SLOT,
+            )
+            .instruction();
+
+        process_with_counter(
+            &mollusk,
+            &instruction,
+            100,
+            &[
+                Check::success(),
+                Check::account(&counter_pubkey)
+                    .data(bytemuck::bytes_of(&Counter {
+                        count: U64LE::new(105),
+                    }))
+                    .build(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_client_increase_rejects_stale_expected_count() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, _) = Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let instruction = IncreaseBuilder::new()
+            .authority(owner)
+            .counter(counter_pubkey)
+            .step(1)
+            .expected_count(99)
+            .expiry_slot(0)
+            .flags(MutateCounterOptions::FLAG_EXPECTED_COUNT)
+            .instruction();
+
+        process_with_counter(
+            &mollusk,
+            &instruction,
+            100,
+            &[Check::err(ProgramError::Custom(
+                CounterError::StaleCount as u32,
+            ))],
+        );
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use counter::{
+        instructions::{CreateCounterInstructionData, MutateCounterOptions},
+        state::Counter,
+    };
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_counter_layout() {
+        assert_layout!(Counter, size = 8, {
+            count => 0,
+        });
+    }
+
+    #[test]
+    fn test_create_counter_instruction_data_layout() {
+        assert_layout!(CreateCounterInstructionData, size = 9, {
+            initial_value => 0,
+            bump => 8,
+        });
+    }
+
+    #[test]
+    fn test_mutate_counter_options_layout() {
+        assert_layout!(MutateCounterOptions, size = 25, {
+            step => 0,
+            expected_count => 8,
+            expiry_slot => 16,
+            flags => 24,
+        });
+    }
+}
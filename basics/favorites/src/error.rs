@@ -1,13 +1,17 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio_helper::program_error;
 
-/// Errors that may be returned by myproject.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum MyProjectError {
-    Invalid,
-}
-
-impl From<MyProjectError> for ProgramError {
-    fn from(e: MyProjectError) -> Self {
-        ProgramError::Custom(e as u32)
+program_error! {
+    /// Errors returned by the favorites program
+    pub enum FavoritesError {
+        /// The favorites list already holds `MAX_FAVORITES` items
+        ListFull = 6001,
+        /// No favorite at the requested index
+        IndexOutOfBounds = 6002,
+        /// The item is already in the favorites list
+        DuplicateFavorite = 6003,
+        /// The favorites list belongs to a different user
+        InvalidListOwner = 6004,
+        /// Favorites list items must not be all zeroes
+        EmptyFavorite = 6005,
     }
 }
@@ -0,0 +1,100 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey, ProgramResult};
+use pinocchio_helper::{
+    checks::{require_owner, require_pda},
+    cpi::create_pda_account,
+    instruction_accounts, seeds,
+};
+
+use crate::{
+    constants::FAVORITES_LIST_SEED, processor::load_favorites_list_mut, state::FavoritesList,
+};
+
+instruction_accounts! {
+    pub struct AddFavoriteIxsAccounts {
+        [signer, writable] user,
+        [writable] favorites_list,
+        system_program,
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct AddFavoriteIxsData {
+    /// Item name, UTF-8 padded with zeroes; must not be empty
+    pub item: [u8; 32],
+}
+
+impl AddFavoriteIxsData {
+    pub const LEN: usize = core::mem::size_of::<AddFavoriteIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for AddFavoriteIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct AddFavorite<'info> {
+    pub accounts: AddFavoriteIxsAccounts<'info>,
+    pub data: AddFavoriteIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], AddFavoriteIxsData)> for AddFavorite<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], AddFavoriteIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = AddFavoriteIxsAccounts::try_from(accounts)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> AddFavorite<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        if self.accounts.favorites_list.data_len() == 0 {
+            self.create_favorites_list()?;
+        } else {
+            require_owner(1, self.accounts.favorites_list, &crate::ID)?;
+        }
+
+        let list = load_favorites_list_mut(self.accounts.favorites_list, self.accounts.user)?;
+        list.push(self.data.item)?;
+
+        Ok(())
+    }
+
+    fn create_favorites_list(&self) -> ProgramResult {
+        let (list_pubkey, bump) = pubkey::find_program_address(
+            &[FAVORITES_LIST_SEED, self.accounts.user.key().as_ref()],
+            &crate::ID,
+        );
+        require_pda(1, self.accounts.favorites_list, &list_pubkey)?;
+
+        seeds!(seed = [FAVORITES_LIST_SEED, self.accounts.user.key()], bump);
+        create_pda_account(
+            self.accounts.user,
+            self.accounts.favorites_list,
+            FavoritesList::LEN,
+            &crate::ID,
+            &seed,
+        )?;
+
+        let list = unsafe {
+            bytemuck::try_from_bytes_mut::<FavoritesList>(
+                self.accounts.favorites_list.borrow_mut_data_unchecked(),
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        };
+        list.owner = *self.accounts.user.key();
+        list.bump = bump;
+
+        Ok(())
+    }
+}
@@ -1,33 +1,12 @@
 use crate::{constants::FAVORITES_SEED, state::Favorites};
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey, ProgramResult};
+use pinocchio_helper::{checks::require_key, instruction_accounts};
 use pinocchio_log::log;
 
-pub struct GetPdaIxsAccounts<'info> {
-    pub user: &'info AccountInfo,
-    pub favorites: &'info AccountInfo,
-}
-
-impl<'info> TryFrom<&'info [AccountInfo]> for GetPdaIxsAccounts<'info> {
-    type Error = ProgramError;
-
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [user, favorites] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-
-        if user.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if favorites.data_len() == 0 {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        if !favorites.is_owned_by(&crate::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-
-        Ok(Self { user, favorites })
+instruction_accounts! {
+    pub struct GetPdaIxsAccounts {
+        user,
+        [data, program_owned] favorites,
     }
 }
 
@@ -40,6 +19,9 @@ impl<'info> TryFrom<&'info [AccountInfo]> for GetPda<'info> {
 
     fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
         let accounts = GetPdaIxsAccounts::try_from(accounts)?;
+        if accounts.user.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
         Ok(Self { accounts })
     }
 }
@@ -55,14 +37,12 @@ impl<'info> GetPda<'info> {
 
         let seeds = &[FAVORITES_SEED, self.accounts.user.key().as_ref()];
         let (favorites_pubkey, _) = pubkey::find_program_address(seeds, &crate::ID);
-        if self.accounts.favorites.key() != &favorites_pubkey {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        require_key(1, self.accounts.favorites, &favorites_pubkey)?;
 
         log!(
             "User {}'s favorite number is {} and favorite color is {}",
             self.accounts.user.key(),
-            u64::from_le_bytes(favorites.number),
+            favorites.number.get(),
             bytemuck::from_bytes::<[u8; 50]>(&favorites.color)
         );
 
@@ -0,0 +1,58 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_helper::instruction_accounts;
+
+use crate::processor::load_favorites_list_mut;
+
+instruction_accounts! {
+    pub struct RemoveFavoriteIxsAccounts {
+        [signer] user,
+        [writable, program_owned] favorites_list,
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct RemoveFavoriteIxsData {
+    pub index: u8,
+}
+
+impl RemoveFavoriteIxsData {
+    pub const LEN: usize = core::mem::size_of::<RemoveFavoriteIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for RemoveFavoriteIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct RemoveFavorite<'info> {
+    pub accounts: RemoveFavoriteIxsAccounts<'info>,
+    pub data: RemoveFavoriteIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], RemoveFavoriteIxsData)> for RemoveFavorite<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], RemoveFavoriteIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RemoveFavoriteIxsAccounts::try_from(accounts)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> RemoveFavorite<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let list = load_favorites_list_mut(self.accounts.favorites_list, self.accounts.user)?;
+        list.remove(self.data.index)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::FavoritesError,
+    state::{Favorites, FavoritesList},
+};
+
+/// Borrow the favorites account data. The caller must have checked ownership.
+pub fn load_favorites_mut(favorites: &AccountInfo) -> Result<&mut Favorites, ProgramError> {
+    unsafe {
+        bytemuck::try_from_bytes_mut::<Favorites>(favorites.borrow_mut_data_unchecked())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Borrow a favorites list and check it belongs to `owner`.
+///
+/// The caller must have checked the list is owned by this program; since only
+/// this program writes `owner`, matching it is enough to tie the list to the user.
+pub fn load_favorites_list<'a>(
+    favorites_list: &'a AccountInfo,
+    owner: &AccountInfo,
+) -> Result<&'a FavoritesList, ProgramError> {
+    let list = unsafe {
+        bytemuck::try_from_bytes::<FavoritesList>(favorites_list.borrow_data_unchecked())
+            .map_err(|_| ProgramError::InvalidAccountData)?
+    };
+
+    if list.owner.ne(owner.key()) {
+        return Err(FavoritesError::InvalidListOwner.into());
+    }
+
+    Ok(list)
+}
+
+/// Mutable counterpart of [`load_favorites_list`], for instructions that
+/// were given the list as writable.
+pub fn load_favorites_list_mut<'a>(
+    favorites_list: &'a AccountInfo,
+    owner: &AccountInfo,
+) -> Result<&'a mut FavoritesList, ProgramError> {
+    let list = unsafe {
+        bytemuck::try_from_bytes_mut::<FavoritesList>(favorites_list.borrow_mut_data_unchecked())
+            .map_err(|_| ProgramError::InvalidAccountData)?
+    };
+
+    if list.owner.ne(owner.key()) {
+        return Err(FavoritesError::InvalidListOwner.into());
+    }
+
+    Ok(list)
+}
@@ -1,3 +1,3 @@
-pub mod pda_utils;
+pub mod accounts;
 
-pub use pda_utils::*;
+pub use accounts::*;
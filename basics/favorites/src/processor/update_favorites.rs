@@ -0,0 +1,85 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey, ProgramResult};
+use pinocchio_helper::{checks::require_pda, instruction_accounts, le::U64LE};
+
+use crate::{constants::FAVORITES_SEED, processor::load_favorites_mut};
+
+instruction_accounts! {
+    pub struct UpdateFavoritesIxsAccounts {
+        [signer] user,
+        [writable, program_owned, data] favorites,
+    }
+}
+
+/// Replacement values for every field except the bump, which is fixed at creation
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct UpdateFavoritesIxsData {
+    #[idl_type("u64")]
+    pub number: U64LE,
+    pub color: [u8; 50],
+    pub hobby1: [u8; 50],
+    pub hobby2: [u8; 50],
+    pub hobby3: [u8; 50],
+    pub hobby4: [u8; 50],
+    pub hobby5: [u8; 50],
+}
+
+impl UpdateFavoritesIxsData {
+    pub const LEN: usize = core::mem::size_of::<UpdateFavoritesIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for UpdateFavoritesIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct UpdateFavorites<'info> {
+    pub accounts: UpdateFavoritesIxsAccounts<'info>,
+    pub data: UpdateFavoritesIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], UpdateFavoritesIxsData)> for UpdateFavorites<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], UpdateFavoritesIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = UpdateFavoritesIxsAccounts::try_from(accounts)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> UpdateFavorites<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let favorites = load_favorites_mut(self.accounts.favorites)?;
+
+        // Only the user the PDA was derived from can update it
+        let favorites_pubkey = pubkey::create_program_address(
+            &[
+                FAVORITES_SEED,
+                self.accounts.user.key().as_ref(),
+                &[favorites.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+        require_pda(1, self.accounts.favorites, &favorites_pubkey)?;
+
+        favorites.number = self.data.number;
+        favorites.color = self.data.color;
+        favorites.hobby1 = self.data.hobby1;
+        favorites.hobby2 = self.data.hobby2;
+        favorites.hobby3 = self.data.hobby3;
+        favorites.hobby4 = self.data.hobby4;
+        favorites.hobby5 = self.data.hobby5;
+
+        Ok(())
+    }
+}
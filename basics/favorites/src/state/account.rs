@@ -1,10 +1,12 @@
 use bytemuck::{Pod, Zeroable};
+use pinocchio_helper::le::U64LE;
 use shank::ShankAccount;
 
 #[derive(Clone, Copy, Pod, Zeroable, ShankAccount)]
 #[repr(C)]
 pub struct Favorites {
-    pub number: [u8; 8],
+    #[idl_type("u64")]
+    pub number: U64LE,
     pub color: [u8; 50],
     pub hobby1: [u8; 50],
     pub hobby2: [u8; 50],
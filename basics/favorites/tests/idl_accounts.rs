@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use favorites::processor::{
+        AddFavoriteIxsAccounts, CreatePdaIxsAccounts, GetFavoritesIxsAccounts, GetPdaIxsAccounts,
+        RemoveFavoriteIxsAccounts, UpdateFavoritesIxsAccounts,
+    };
+    use test_support::idl::assert_accounts_match;
+
+    const IDL: &str = include_str!("../../../idl/favorites.json");
+
+    #[test]
+    fn test_create_pda_accounts_match_idl() {
+        assert_accounts_match(IDL, "CreatePda", CreatePdaIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_get_pda_accounts_match_idl() {
+        assert_accounts_match(IDL, "GetPda", GetPdaIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_update_favorites_accounts_match_idl() {
+        assert_accounts_match(IDL, "UpdateFavorites", UpdateFavoritesIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_add_favorite_accounts_match_idl() {
+        assert_accounts_match(IDL, "AddFavorite", AddFavoriteIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_remove_favorite_accounts_match_idl() {
+        assert_accounts_match(IDL, "RemoveFavorite", RemoveFavoriteIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_get_favorites_accounts_match_idl() {
+        assert_accounts_match(IDL, "GetFavorites", GetFavoritesIxsAccounts::ACCOUNTS);
+    }
+}
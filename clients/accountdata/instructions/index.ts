@@ -6,4 +6,6 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './closeAddressInfo';
 export * from './create';
+export * from './updateAddressInfo';
@@ -12,7 +12,11 @@ import {
   type Address,
   type ReadonlyUint8Array,
 } from '@solana/kit';
-import { type ParsedCreateInstruction } from '../instructions';
+import {
+  type ParsedCloseAddressInfoInstruction,
+  type ParsedCreateInstruction,
+  type ParsedUpdateAddressInfoInstruction,
+} from '../instructions';
 
 export const ACCOUNT_DATA_PROGRAM_ADDRESS =
   'Fruv5QjqNDXvvYT2hw4FjhsT5aa11bHAPtMQH46mg3SS' as Address<'Fruv5QjqNDXvvYT2hw4FjhsT5aa11bHAPtMQH46mg3SS'>;
@@ -23,6 +27,8 @@ export enum AccountDataAccount {
 
 export enum AccountDataInstruction {
   Create,
+  UpdateAddressInfo,
+  CloseAddressInfo,
 }
 
 export function identifyAccountDataInstruction(
@@ -32,6 +38,12 @@ export function identifyAccountDataInstruction(
   if (containsBytes(data, getU8Encoder().encode(0), 0)) {
     return AccountDataInstruction.Create;
   }
+  if (containsBytes(data, getU8Encoder().encode(1), 0)) {
+    return AccountDataInstruction.UpdateAddressInfo;
+  }
+  if (containsBytes(data, getU8Encoder().encode(2), 0)) {
+    return AccountDataInstruction.CloseAddressInfo;
+  }
   throw new Error(
     'The provided instruction could not be identified as a accountData instruction.',
   );
@@ -39,6 +51,13 @@ export function identifyAccountDataInstruction(
 
 export type ParsedAccountDataInstruction<
   TProgram extends string = 'Fruv5QjqNDXvvYT2hw4FjhsT5aa11bHAPtMQH46mg3SS',
-> = {
-  instructionType: AccountDataInstruction.Create;
-} & ParsedCreateInstruction<TProgram>;
+> =
+  | ({
+      instructionType: AccountDataInstruction.Create;
+    } & ParsedCreateInstruction<TProgram>)
+  | ({
+      instructionType: AccountDataInstruction.UpdateAddressInfo;
+    } & ParsedUpdateAddressInfoInstruction<TProgram>)
+  | ({
+      instructionType: AccountDataInstruction.CloseAddressInfo;
+    } & ParsedCloseAddressInfoInstruction<TProgram>);
@@ -0,0 +1,65 @@
+import { expect, test } from 'bun:test';
+import { address, createNoopSigner } from '@solana/kit';
+
+import {
+  getDecreaseInstruction,
+  getIncreaseInstruction,
+  getIncreaseInstructionDataDecoder,
+} from './counter';
+
+const authority = createNoopSigner(address('11111111111111111111111111111112'));
+const counter = address('11111111111111111111111111111113');
+
+// Mirrors `MutateCounterOptions` in basics/counter: the discriminator, then
+// step, expected_count and expiry_slot as little-endian u64s and a flags byte.
+const optionsBytes = (
+  discriminator: number,
+  step: bigint,
+  expectedCount: bigint,
+  expirySlot: bigint,
+  flags: number
+): Uint8Array => {
+  const bytes = new Uint8Array(1 + 3 * 8 + 1);
+  const view = new DataView(bytes.buffer);
+  bytes[0] = discriminator;
+  view.setBigUint64(1, step, true);
+  view.setBigUint64(9, expectedCount, true);
+  view.setBigUint64(17, expirySlot, true);
+  bytes[25] = flags;
+  return bytes;
+};
+
+test('increase encodes the options in the program layout', () => {
+  const instruction = getIncreaseInstruction({
+    authority,
+    counter,
+    step: 5,
+    expectedCount: 100,
+    expirySlot: 10,
+    flags: 0b11,
+  });
+  expect(instruction.data).toEqual(optionsBytes(1, 5n, 100n, 10n, 0b11));
+});
+
+test('decrease encodes the options in the program layout', () => {
+  const instruction = getDecreaseInstruction({
+    authority,
+    counter,
+    step: 1,
+    expectedCount: 0,
+    expirySlot: 0,
+    flags: 0,
+  });
+  expect(instruction.data).toEqual(optionsBytes(2, 1n, 0n, 0n, 0));
+});
+
+test('increase options round-trip through the decoder', () => {
+  const data = optionsBytes(1, 7n, 3n, 42n, 0b10);
+  expect(getIncreaseInstructionDataDecoder().decode(data)).toEqual({
+    discriminator: 1,
+    step: 7n,
+    expectedCount: 3n,
+    expirySlot: 42n,
+    flags: 0b10,
+  });
+});
@@ -13,12 +13,10 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
-  fixDecoderSize,
-  fixEncoderSize,
-  getBytesDecoder,
-  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   type Account,
   type Address,
   type Codec,
@@ -29,19 +27,18 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
-  type ReadonlyUint8Array,
 } from '@solana/kit';
 
-export type Counter = { count: ReadonlyUint8Array };
+export type Counter = { count: bigint };
 
-export type CounterArgs = Counter;
+export type CounterArgs = { count: number | bigint };
 
 export function getCounterEncoder(): Encoder<CounterArgs> {
-  return getStructEncoder([['count', fixEncoderSize(getBytesEncoder(), 8)]]);
+  return getStructEncoder([['count', getU64Encoder()]]);
 }
 
 export function getCounterDecoder(): Decoder<Counter> {
-  return getStructDecoder([['count', fixDecoderSize(getBytesDecoder(), 8)]]);
+  return getStructDecoder([['count', getU64Decoder()]]);
 }
 
 export function getCounterCodec(): Codec<CounterArgs, Counter> {
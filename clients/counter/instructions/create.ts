@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -62,19 +64,34 @@ export type CreateInstruction<
     ]
   >;
 
-export type CreateInstructionData = { discriminator: number };
+export type CreateInstructionData = {
+  discriminator: number;
+  initialValue: bigint;
+  bump: number;
+};
 
-export type CreateInstructionDataArgs = {};
+export type CreateInstructionDataArgs = {
+  initialValue: number | bigint;
+  bump: number;
+};
 
 export function getCreateInstructionDataEncoder(): Encoder<CreateInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['initialValue', getU64Encoder()],
+      ['bump', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: CREATE_DISCRIMINATOR }),
   );
 }
 
 export function getCreateInstructionDataDecoder(): Decoder<CreateInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['initialValue', getU64Decoder()],
+    ['bump', getU8Decoder()],
+  ]);
 }
 
 export function getCreateInstructionDataCodec(): Codec<
@@ -98,6 +115,8 @@ export type CreateInput<
   counter: Address<TAccountCounter>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
+  initialValue: CreateInstructionDataArgs['initialValue'];
+  bump: CreateInstructionDataArgs['bump'];
 };
 
 export function getCreateInstruction<
@@ -128,6 +147,9 @@ export function getCreateInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -142,7 +164,9 @@ export function getCreateInstruction<
       getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
-    data: getCreateInstructionDataEncoder().encode({}),
+    data: getCreateInstructionDataEncoder().encode(
+      args as CreateInstructionDataArgs,
+    ),
   } as CreateInstruction<
     TProgramAddress,
     TAccountMaker,
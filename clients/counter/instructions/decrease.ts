@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -55,19 +57,42 @@ export type DecreaseInstruction<
     ]
   >;
 
-export type DecreaseInstructionData = { discriminator: number };
+export type DecreaseInstructionData = {
+  discriminator: number;
+  step: bigint;
+  expectedCount: bigint;
+  expirySlot: bigint;
+  flags: number;
+};
 
-export type DecreaseInstructionDataArgs = {};
+export type DecreaseInstructionDataArgs = {
+  step: number | bigint;
+  expectedCount: number | bigint;
+  expirySlot: number | bigint;
+  flags: number;
+};
 
 export function getDecreaseInstructionDataEncoder(): Encoder<DecreaseInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['step', getU64Encoder()],
+      ['expectedCount', getU64Encoder()],
+      ['expirySlot', getU64Encoder()],
+      ['flags', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: DECREASE_DISCRIMINATOR }),
   );
 }
 
 export function getDecreaseInstructionDataDecoder(): Decoder<DecreaseInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['step', getU64Decoder()],
+    ['expectedCount', getU64Decoder()],
+    ['expirySlot', getU64Decoder()],
+    ['flags', getU8Decoder()],
+  ]);
 }
 
 export function getDecreaseInstructionDataCodec(): Codec<
@@ -88,6 +113,10 @@ export type DecreaseInput<
   authority: TransactionSigner<TAccountAuthority>;
   /** The counter account */
   counter: Address<TAccountCounter>;
+  step: DecreaseInstructionDataArgs['step'];
+  expectedCount: DecreaseInstructionDataArgs['expectedCount'];
+  expirySlot: DecreaseInstructionDataArgs['expirySlot'];
+  flags: DecreaseInstructionDataArgs['flags'];
 };
 
 export function getDecreaseInstruction<
@@ -111,6 +140,9 @@ export function getDecreaseInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
@@ -118,7 +150,9 @@ export function getDecreaseInstruction<
       getAccountMeta(accounts.counter),
     ],
     programAddress,
-    data: getDecreaseInstructionDataEncoder().encode({}),
+    data: getDecreaseInstructionDataEncoder().encode(
+      args as DecreaseInstructionDataArgs,
+    ),
   } as DecreaseInstruction<TProgramAddress, TAccountAuthority, TAccountCounter>;
 
   return instruction;
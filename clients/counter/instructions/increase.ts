@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -55,19 +57,42 @@ export type IncreaseInstruction<
     ]
   >;
 
-export type IncreaseInstructionData = { discriminator: number };
+export type IncreaseInstructionData = {
+  discriminator: number;
+  step: bigint;
+  expectedCount: bigint;
+  expirySlot: bigint;
+  flags: number;
+};
 
-export type IncreaseInstructionDataArgs = {};
+export type IncreaseInstructionDataArgs = {
+  step: number | bigint;
+  expectedCount: number | bigint;
+  expirySlot: number | bigint;
+  flags: number;
+};
 
 export function getIncreaseInstructionDataEncoder(): Encoder<IncreaseInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['step', getU64Encoder()],
+      ['expectedCount', getU64Encoder()],
+      ['expirySlot', getU64Encoder()],
+      ['flags', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: INCREASE_DISCRIMINATOR }),
   );
 }
 
 export function getIncreaseInstructionDataDecoder(): Decoder<IncreaseInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['step', getU64Decoder()],
+    ['expectedCount', getU64Decoder()],
+    ['expirySlot', getU64Decoder()],
+    ['flags', getU8Decoder()],
+  ]);
 }
 
 export function getIncreaseInstructionDataCodec(): Codec<
@@ -88,6 +113,10 @@ export type IncreaseInput<
   authority: TransactionSigner<TAccountAuthority>;
   /** The counter account */
   counter: Address<TAccountCounter>;
+  step: IncreaseInstructionDataArgs['step'];
+  expectedCount: IncreaseInstructionDataArgs['expectedCount'];
+  expirySlot: IncreaseInstructionDataArgs['expirySlot'];
+  flags: IncreaseInstructionDataArgs['flags'];
 };
 
 export function getIncreaseInstruction<
@@ -111,6 +140,9 @@ export function getIncreaseInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
@@ -118,7 +150,9 @@ export function getIncreaseInstruction<
       getAccountMeta(accounts.counter),
     ],
     programAddress,
-    data: getIncreaseInstructionDataEncoder().encode({}),
+    data: getIncreaseInstructionDataEncoder().encode(
+      args as IncreaseInstructionDataArgs,
+    ),
   } as IncreaseInstruction<TProgramAddress, TAccountAuthority, TAccountCounter>;
 
   return instruction;
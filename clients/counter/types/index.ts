@@ -6,4 +6,6 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './createCounterInstructionData';
+export * from './mutateCounterOptions';
 export * from './mutationType';
@@ -6,5 +6,4 @@
  * @see https://github.com/codama-idl/codama
  */
 
-export * from './createPda';
-export * from './getPda';
+export * from './createToken';
@@ -12,10 +12,7 @@ import {
   type Address,
   type ReadonlyUint8Array,
 } from '@solana/kit';
-import {
-  type ParsedCreatePdaInstruction,
-  type ParsedGetPdaInstruction,
-} from '../instructions';
+import { type ParsedCreateTokenInstruction } from '../instructions';
 
 export const CREATE_TOKEN_PROGRAM_ADDRESS =
   'AyTbwR15mfGFrfJw2qArB46wXWWZdXwFye5fYouiB3Ci' as Address<'AyTbwR15mfGFrfJw2qArB46wXWWZdXwFye5fYouiB3Ci'>;
@@ -25,8 +22,7 @@ export enum CreateTokenAccount {
 }
 
 export enum CreateTokenInstruction {
-  CreatePda,
-  GetPda,
+  CreateToken,
 }
 
 export function identifyCreateTokenInstruction(
@@ -34,10 +30,7 @@ export function identifyCreateTokenInstruction(
 ): CreateTokenInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
   if (containsBytes(data, getU8Encoder().encode(0), 0)) {
-    return CreateTokenInstruction.CreatePda;
-  }
-  if (containsBytes(data, getU8Encoder().encode(1), 0)) {
-    return CreateTokenInstruction.GetPda;
+    return CreateTokenInstruction.CreateToken;
   }
   throw new Error(
     'The provided instruction could not be identified as a createToken instruction.',
@@ -46,10 +39,6 @@ export function identifyCreateTokenInstruction(
 
 export type ParsedCreateTokenInstruction<
   TProgram extends string = 'AyTbwR15mfGFrfJw2qArB46wXWWZdXwFye5fYouiB3Ci',
-> =
-  | ({
-      instructionType: CreateTokenInstruction.CreatePda;
-    } & ParsedCreatePdaInstruction<TProgram>)
-  | ({
-      instructionType: CreateTokenInstruction.GetPda;
-    } & ParsedGetPdaInstruction<TProgram>);
+> = {
+  instructionType: CreateTokenInstruction.CreateToken;
+} & ParsedCreateTokenInstruction<TProgram>;
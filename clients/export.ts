@@ -0,0 +1,120 @@
+import { writeFileSync } from 'node:fs';
+
+import {
+  AccountRole,
+  appendTransactionMessageInstructions,
+  compileTransaction,
+  createTransactionMessage,
+  getBase64EncodedWireTransaction,
+  pipe,
+  setTransactionMessageFeePayer,
+  setTransactionMessageLifetimeUsingBlockhash,
+  type Address,
+  type GetLatestBlockhashApi,
+  type IInstruction,
+  type Rpc,
+} from '@solana/kit';
+
+/**
+ * Export mode: build transactions without a keypair so they can be signed
+ * elsewhere — a browser wallet adapter, or a multisig such as Squads.
+ *
+ * Set `CLIENT_MODE=export` to make scripts call `exportTransaction` instead
+ * of `sendAndConfirmTransaction`. Nothing here reads the local keypair file.
+ */
+export type ClientMode = 'send' | 'export';
+
+export const getClientMode = (): ClientMode =>
+  process.env.CLIENT_MODE === 'export' ? 'export' : 'send';
+
+export type ExportedTransaction = {
+  /** Unsigned v0 wire transaction, base64 encoded, signature slots zeroed */
+  transaction: string;
+  feePayer: Address;
+  /** Blockhash the transaction expires with; re-export once it lapses */
+  blockhash: string;
+  lastValidBlockHeight: bigint;
+  /** One readable line per instruction, then one per account */
+  summary: string[];
+};
+
+const roleLabel = (role: AccountRole) => {
+  switch (role) {
+    case AccountRole.WRITABLE_SIGNER:
+      return 'signer, writable';
+    case AccountRole.READONLY_SIGNER:
+      return 'signer';
+    case AccountRole.WRITABLE:
+      return 'writable';
+    default:
+      return 'readonly';
+  }
+};
+
+/**
+ * Describe `instructions` for a human reviewer. `programNames` maps program
+ * addresses to names; unknown programs are shown by address.
+ */
+export const summarizeInstructions = (
+  instructions: readonly IInstruction[],
+  programNames: Record<string, string> = {}
+): string[] =>
+  instructions.flatMap((ix, i) => {
+    const program = programNames[ix.programAddress] ?? ix.programAddress;
+    const data = ix.data ?? new Uint8Array();
+    const discriminator = data.length > 0 ? ` discriminator ${data[0]}` : '';
+    return [
+      `#${i} ${program}${discriminator} (${data.length} data bytes)`,
+      ...(ix.accounts ?? []).map(
+        (account, j) => `  ${j}: ${account.address} [${roleLabel(account.role)}]`
+      ),
+    ];
+  });
+
+/**
+ * Compile `instructions` into an unsigned v0 transaction paid by `feePayer`.
+ * The fee payer only needs to be an address; it signs in the external wallet.
+ */
+export const exportTransaction = async ({
+  rpc,
+  feePayer,
+  instructions,
+  programNames,
+}: {
+  rpc: Rpc<GetLatestBlockhashApi>;
+  feePayer: Address;
+  instructions: readonly IInstruction[];
+  programNames?: Record<string, string>;
+}): Promise<ExportedTransaction> => {
+  const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
+
+  const message = pipe(
+    createTransactionMessage({ version: 0 }),
+    (m) => setTransactionMessageFeePayer(feePayer, m),
+    (m) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, m),
+    (m) => appendTransactionMessageInstructions(instructions, m)
+  );
+
+  return {
+    transaction: getBase64EncodedWireTransaction(compileTransaction(message)),
+    feePayer,
+    blockhash: latestBlockhash.blockhash,
+    lastValidBlockHeight: latestBlockhash.lastValidBlockHeight,
+    summary: summarizeInstructions(instructions, programNames),
+  };
+};
+
+/** Write an export as JSON, e.g. for a Squads "import transaction" step. */
+export const writeExportedTransaction = (
+  path: string,
+  exported: ExportedTransaction
+) => {
+  writeFileSync(
+    path,
+    JSON.stringify(
+      exported,
+      (_, value) => (typeof value === 'bigint' ? value.toString() : value),
+      2
+    )
+  );
+};
@@ -19,6 +19,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -35,7 +37,7 @@ import {
 } from '@solana/kit';
 
 export type Favorites = {
-  number: ReadonlyUint8Array;
+  number: bigint;
   color: ReadonlyUint8Array;
   hobby1: ReadonlyUint8Array;
   hobby2: ReadonlyUint8Array;
@@ -45,11 +47,20 @@ export type Favorites = {
   bump: number;
 };
 
-export type FavoritesArgs = Favorites;
+export type FavoritesArgs = {
+  number: number | bigint;
+  color: ReadonlyUint8Array;
+  hobby1: ReadonlyUint8Array;
+  hobby2: ReadonlyUint8Array;
+  hobby3: ReadonlyUint8Array;
+  hobby4: ReadonlyUint8Array;
+  hobby5: ReadonlyUint8Array;
+  bump: number;
+};
 
 export function getFavoritesEncoder(): Encoder<FavoritesArgs> {
   return getStructEncoder([
-    ['number', fixEncoderSize(getBytesEncoder(), 8)],
+    ['number', getU64Encoder()],
     ['color', fixEncoderSize(getBytesEncoder(), 50)],
     ['hobby1', fixEncoderSize(getBytesEncoder(), 50)],
     ['hobby2', fixEncoderSize(getBytesEncoder(), 50)],
@@ -62,7 +73,7 @@ export function getFavoritesEncoder(): Encoder<FavoritesArgs> {
 
 export function getFavoritesDecoder(): Decoder<Favorites> {
   return getStructDecoder([
-    ['number', fixDecoderSize(getBytesDecoder(), 8)],
+    ['number', getU64Decoder()],
     ['color', fixDecoderSize(getBytesDecoder(), 50)],
     ['hobby1', fixDecoderSize(getBytesDecoder(), 50)],
     ['hobby2', fixDecoderSize(getBytesDecoder(), 50)],
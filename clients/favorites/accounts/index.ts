@@ -7,3 +7,4 @@
  */
 
 export * from './favorites';
+export * from './favoritesList';
@@ -8,8 +8,14 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -18,11 +24,18 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { FAVORITES_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const CREATE_PDA_DISCRIMINATOR = 0;
 
@@ -32,24 +45,82 @@ export function getCreatePdaDiscriminatorBytes() {
 
 export type CreatePdaInstruction<
   TProgram extends string = typeof FAVORITES_PROGRAM_ADDRESS,
+  TAccountUser extends string | IAccountMeta<string> = string,
+  TAccountFavorites extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
-  IInstructionWithAccounts<TRemainingAccounts>;
+  IInstructionWithAccounts<
+    [
+      TAccountUser extends string
+        ? WritableSignerAccount<TAccountUser> &
+            IAccountSignerMeta<TAccountUser>
+        : TAccountUser,
+      TAccountFavorites extends string
+        ? WritableAccount<TAccountFavorites>
+        : TAccountFavorites,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
 
-export type CreatePdaInstructionData = { discriminator: number };
+export type CreatePdaInstructionData = {
+  discriminator: number;
+  number: bigint;
+  color: ReadonlyUint8Array;
+  hobby1: ReadonlyUint8Array;
+  hobby2: ReadonlyUint8Array;
+  hobby3: ReadonlyUint8Array;
+  hobby4: ReadonlyUint8Array;
+  hobby5: ReadonlyUint8Array;
+  bump: number;
+};
 
-export type CreatePdaInstructionDataArgs = {};
+export type CreatePdaInstructionDataArgs = {
+  number: number | bigint;
+  color: ReadonlyUint8Array;
+  hobby1: ReadonlyUint8Array;
+  hobby2: ReadonlyUint8Array;
+  hobby3: ReadonlyUint8Array;
+  hobby4: ReadonlyUint8Array;
+  hobby5: ReadonlyUint8Array;
+  bump: number;
+};
 
 export function getCreatePdaInstructionDataEncoder(): Encoder<CreatePdaInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['number', getU64Encoder()],
+      ['color', fixEncoderSize(getBytesEncoder(), 50)],
+      ['hobby1', fixEncoderSize(getBytesEncoder(), 50)],
+      ['hobby2', fixEncoderSize(getBytesEncoder(), 50)],
+      ['hobby3', fixEncoderSize(getBytesEncoder(), 50)],
+      ['hobby4', fixEncoderSize(getBytesEncoder(), 50)],
+      ['hobby5', fixEncoderSize(getBytesEncoder(), 50)],
+      ['bump', getU8Encoder()],
+    ]),
     (value) => ({ ...value, discriminator: CREATE_PDA_DISCRIMINATOR }),
   );
 }
 
 export function getCreatePdaInstructionDataDecoder(): Decoder<CreatePdaInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['number', getU64Decoder()],
+    ['color', fixDecoderSize(getBytesDecoder(), 50)],
+    ['hobby1', fixDecoderSize(getBytesDecoder(), 50)],
+    ['hobby2', fixDecoderSize(getBytesDecoder(), 50)],
+    ['hobby3', fixDecoderSize(getBytesDecoder(), 50)],
+    ['hobby4', fixDecoderSize(getBytesDecoder(), 50)],
+    ['hobby5', fixDecoderSize(getBytesDecoder(), 50)],
+    ['bump', getU8Decoder()],
+  ]);
 }
 
 export function getCreatePdaInstructionDataCodec(): Codec<
@@ -62,36 +133,126 @@ export function getCreatePdaInstructionDataCodec(): Codec<
   );
 }
 
-export type CreatePdaInput = {};
+export type CreatePdaInput<
+  TAccountUser extends string = string,
+  TAccountFavorites extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  /** Owner of the favorites, pays for the PDA */
+  user: TransactionSigner<TAccountUser>;
+  /** Favorites PDA derived from the user */
+  favorites: Address<TAccountFavorites>;
+  /** The system program */
+  systemProgram?: Address<TAccountSystemProgram>;
+  number: CreatePdaInstructionDataArgs['number'];
+  color: CreatePdaInstructionDataArgs['color'];
+  hobby1: CreatePdaInstructionDataArgs['hobby1'];
+  hobby2: CreatePdaInstructionDataArgs['hobby2'];
+  hobby3: CreatePdaInstructionDataArgs['hobby3'];
+  hobby4: CreatePdaInstructionDataArgs['hobby4'];
+  hobby5: CreatePdaInstructionDataArgs['hobby5'];
+  bump: CreatePdaInstructionDataArgs['bump'];
+};
 
 export function getCreatePdaInstruction<
+  TAccountUser extends string,
+  TAccountFavorites extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof FAVORITES_PROGRAM_ADDRESS,
->(config?: {
-  programAddress?: TProgramAddress;
-}): CreatePdaInstruction<TProgramAddress> {
+>(
+  input: CreatePdaInput<TAccountUser, TAccountFavorites, TAccountSystemProgram>,
+  config?: { programAddress?: TProgramAddress },
+): CreatePdaInstruction<
+  TProgramAddress,
+  TAccountUser,
+  TAccountFavorites,
+  TAccountSystemProgram
+> {
   // Program address.
   const programAddress = config?.programAddress ?? FAVORITES_PROGRAM_ADDRESS;
 
+  // Original accounts.
+  const originalAccounts = {
+    user: { value: input.user ?? null, isWritable: true },
+    favorites: { value: input.favorites ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
+    accounts: [
+      getAccountMeta(accounts.user),
+      getAccountMeta(accounts.favorites),
+      getAccountMeta(accounts.systemProgram),
+    ],
     programAddress,
-    data: getCreatePdaInstructionDataEncoder().encode({}),
-  } as CreatePdaInstruction<TProgramAddress>;
+    data: getCreatePdaInstructionDataEncoder().encode(
+      args as CreatePdaInstructionDataArgs,
+    ),
+  } as CreatePdaInstruction<
+    TProgramAddress,
+    TAccountUser,
+    TAccountFavorites,
+    TAccountSystemProgram
+  >;
 
   return instruction;
 }
 
 export type ParsedCreatePdaInstruction<
   TProgram extends string = typeof FAVORITES_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
 > = {
   programAddress: Address<TProgram>;
+  accounts: {
+    /** Owner of the favorites, pays for the PDA */
+    user: TAccountMetas[0];
+    /** Favorites PDA derived from the user */
+    favorites: TAccountMetas[1];
+    /** The system program */
+    systemProgram: TAccountMetas[2];
+  };
   data: CreatePdaInstructionData;
 };
 
-export function parseCreatePdaInstruction<TProgram extends string>(
-  instruction: IInstruction<TProgram> & IInstructionWithData<Uint8Array>,
-): ParsedCreatePdaInstruction<TProgram> {
+export function parseCreatePdaInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>,
+): ParsedCreatePdaInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
+    accounts: {
+      user: getNextAccount(),
+      favorites: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
     data: getCreatePdaInstructionDataDecoder().decode(instruction.data),
   };
 }
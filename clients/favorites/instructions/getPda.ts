@@ -21,8 +21,10 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
 } from '@solana/kit';
 import { FAVORITES_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const GET_PDA_DISCRIMINATOR = 1;
 
@@ -32,10 +34,22 @@ export function getGetPdaDiscriminatorBytes() {
 
 export type GetPdaInstruction<
   TProgram extends string = typeof FAVORITES_PROGRAM_ADDRESS,
+  TAccountUser extends string | IAccountMeta<string> = string,
+  TAccountFavorites extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
-  IInstructionWithAccounts<TRemainingAccounts>;
+  IInstructionWithAccounts<
+    [
+      TAccountUser extends string
+        ? ReadonlyAccount<TAccountUser>
+        : TAccountUser,
+      TAccountFavorites extends string
+        ? ReadonlyAccount<TAccountFavorites>
+        : TAccountFavorites,
+      ...TRemainingAccounts,
+    ]
+  >;
 
 export type GetPdaInstructionData = { discriminator: number };
 
@@ -62,36 +76,88 @@ export function getGetPdaInstructionDataCodec(): Codec<
   );
 }
 
-export type GetPdaInput = {};
+export type GetPdaInput<
+  TAccountUser extends string = string,
+  TAccountFavorites extends string = string,
+> = {
+  /** Owner of the favorites */
+  user: Address<TAccountUser>;
+  /** Favorites PDA derived from the user */
+  favorites: Address<TAccountFavorites>;
+};
 
 export function getGetPdaInstruction<
+  TAccountUser extends string,
+  TAccountFavorites extends string,
   TProgramAddress extends Address = typeof FAVORITES_PROGRAM_ADDRESS,
->(config?: {
-  programAddress?: TProgramAddress;
-}): GetPdaInstruction<TProgramAddress> {
+>(
+  input: GetPdaInput<TAccountUser, TAccountFavorites>,
+  config?: { programAddress?: TProgramAddress },
+): GetPdaInstruction<TProgramAddress, TAccountUser, TAccountFavorites> {
   // Program address.
   const programAddress = config?.programAddress ?? FAVORITES_PROGRAM_ADDRESS;
 
+  // Original accounts.
+  const originalAccounts = {
+    user: { value: input.user ?? null, isWritable: false },
+    favorites: { value: input.favorites ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
+    accounts: [
+      getAccountMeta(accounts.user),
+      getAccountMeta(accounts.favorites),
+    ],
     programAddress,
     data: getGetPdaInstructionDataEncoder().encode({}),
-  } as GetPdaInstruction<TProgramAddress>;
+  } as GetPdaInstruction<TProgramAddress, TAccountUser, TAccountFavorites>;
 
   return instruction;
 }
 
 export type ParsedGetPdaInstruction<
   TProgram extends string = typeof FAVORITES_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
 > = {
   programAddress: Address<TProgram>;
+  accounts: {
+    /** Owner of the favorites */
+    user: TAccountMetas[0];
+    /** Favorites PDA derived from the user */
+    favorites: TAccountMetas[1];
+  };
   data: GetPdaInstructionData;
 };
 
-export function parseGetPdaInstruction<TProgram extends string>(
-  instruction: IInstruction<TProgram> & IInstructionWithData<Uint8Array>,
-): ParsedGetPdaInstruction<TProgram> {
+export function parseGetPdaInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>,
+): ParsedGetPdaInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 2) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
+    accounts: {
+      user: getNextAccount(),
+      favorites: getNextAccount(),
+    },
     data: getGetPdaInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -6,5 +6,9 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addFavorite';
 export * from './createPda';
+export * from './getFavorites';
 export * from './getPda';
+export * from './removeFavorite';
+export * from './updateFavorites';
@@ -13,8 +13,12 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddFavoriteInstruction,
   type ParsedCreatePdaInstruction,
+  type ParsedGetFavoritesInstruction,
   type ParsedGetPdaInstruction,
+  type ParsedRemoveFavoriteInstruction,
+  type ParsedUpdateFavoritesInstruction,
 } from '../instructions';
 
 export const FAVORITES_PROGRAM_ADDRESS =
@@ -22,11 +26,16 @@ export const FAVORITES_PROGRAM_ADDRESS =
 
 export enum FavoritesAccount {
   Favorites,
+  FavoritesList,
 }
 
 export enum FavoritesInstruction {
   CreatePda,
   GetPda,
+  UpdateFavorites,
+  AddFavorite,
+  RemoveFavorite,
+  GetFavorites,
 }
 
 export function identifyFavoritesInstruction(
@@ -39,6 +48,18 @@ export function identifyFavoritesInstruction(
   if (containsBytes(data, getU8Encoder().encode(1), 0)) {
     return FavoritesInstruction.GetPda;
   }
+  if (containsBytes(data, getU8Encoder().encode(2), 0)) {
+    return FavoritesInstruction.UpdateFavorites;
+  }
+  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+    return FavoritesInstruction.AddFavorite;
+  }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return FavoritesInstruction.RemoveFavorite;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return FavoritesInstruction.GetFavorites;
+  }
   throw new Error(
     'The provided instruction could not be identified as a favorites instruction.',
   );
@@ -52,4 +73,16 @@ export type ParsedFavoritesInstruction<
     } & ParsedCreatePdaInstruction<TProgram>)
   | ({
       instructionType: FavoritesInstruction.GetPda;
-    } & ParsedGetPdaInstruction<TProgram>);
+    } & ParsedGetPdaInstruction<TProgram>)
+  | ({
+      instructionType: FavoritesInstruction.UpdateFavorites;
+    } & ParsedUpdateFavoritesInstruction<TProgram>)
+  | ({
+      instructionType: FavoritesInstruction.AddFavorite;
+    } & ParsedAddFavoriteInstruction<TProgram>)
+  | ({
+      instructionType: FavoritesInstruction.RemoveFavorite;
+    } & ParsedRemoveFavoriteInstruction<TProgram>)
+  | ({
+      instructionType: FavoritesInstruction.GetFavorites;
+    } & ParsedGetFavoritesInstruction<TProgram>);
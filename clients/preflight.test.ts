@@ -0,0 +1,105 @@
+import { expect, test } from 'bun:test';
+import {
+  address,
+  lamports,
+  type Address,
+  type MaybeEncodedAccount,
+} from '@solana/kit';
+
+import { checkAccount, type PreflightProblem } from './preflight';
+
+const ACCOUNT = address('11111111111111111111111111111112');
+const PROGRAM = address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
+const SYSTEM = address('11111111111111111111111111111111');
+
+const existing = (
+  overrides: Partial<{
+    data: Uint8Array;
+    executable: boolean;
+    lamports: bigint;
+    programAddress: Address;
+  }> = {}
+): MaybeEncodedAccount => {
+  const data = overrides.data ?? new Uint8Array([7, 1, 2, 3]);
+  return {
+    exists: true,
+    address: ACCOUNT,
+    data,
+    executable: overrides.executable ?? false,
+    lamports: lamports(overrides.lamports ?? 1_000_000n),
+    programAddress: overrides.programAddress ?? PROGRAM,
+    space: BigInt(data.length),
+  };
+};
+
+const missing: MaybeEncodedAccount = { exists: false, address: ACCOUNT };
+
+const problems = (
+  account: MaybeEncodedAccount,
+  expectation: Parameters<typeof checkAccount>[1]
+): PreflightProblem[] =>
+  checkAccount(account, expectation).map((issue) => issue.problem);
+
+test('preflight:passes-matching-account', () => {
+  expect(
+    problems(existing(), {
+      owner: PROGRAM,
+      dataSize: 4,
+      discriminator: new Uint8Array([7]),
+      minLamports: 1_000_000n,
+      executable: false,
+    })
+  ).toEqual([]);
+});
+
+test('preflight:missing', () => {
+  expect(problems(missing, {})).toEqual(['missing']);
+});
+
+test('preflight:already-exists', () => {
+  expect(problems(existing(), { mustExist: false })).toEqual([
+    'already-exists',
+  ]);
+  expect(problems(missing, { mustExist: false })).toEqual([]);
+});
+
+test('preflight:owner', () => {
+  const account = existing({ programAddress: SYSTEM });
+
+  expect(problems(account, { owner: PROGRAM })).toEqual(['owner']);
+});
+
+test('preflight:data-size', () => {
+  expect(problems(existing(), { dataSize: 8 })).toEqual(['data-size']);
+});
+
+test('preflight:discriminator', () => {
+  const discriminator = new Uint8Array([9]);
+
+  expect(problems(existing(), { discriminator })).toEqual(['discriminator']);
+});
+
+test('preflight:balance', () => {
+  const account = existing({ lamports: 10n });
+
+  expect(problems(account, { minLamports: 11n })).toEqual(['balance']);
+});
+
+test('preflight:executable', () => {
+  expect(problems(existing(), { executable: true })).toEqual(['executable']);
+  const program = existing({ executable: true });
+
+  expect(problems(program, { executable: false })).toEqual(['executable']);
+});
+
+test('preflight:reports-every-problem-with-its-label', () => {
+  const issues = checkAccount(existing({ programAddress: SYSTEM }), {
+    label: 'counter',
+    owner: PROGRAM,
+    dataSize: 8,
+  });
+
+  expect(issues.map((issue) => issue.problem)).toEqual(['owner', 'data-size']);
+  expect(issues.every((issue) => issue.label === 'counter')).toBe(true);
+  expect(issues.every((issue) => issue.address === ACCOUNT)).toBe(true);
+});
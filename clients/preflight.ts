@@ -0,0 +1,187 @@
+import {
+  fetchEncodedAccounts,
+  type Address,
+  type GetMultipleAccountsApi,
+  type IInstruction,
+  type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
+  type Rpc,
+} from '@solana/kit';
+
+/**
+ * Pre-flight: fetch every account an instruction touches and check it locally
+ * before submitting, so "account not found" or "wrong owner" surfaces as a
+ * readable report instead of an opaque custom error from the program.
+ *
+ * The programs in this repo store raw Pod structs without a discriminator, so
+ * `dataSize` is usually the check that catches a wrong account; use
+ * `discriminator` for accounts that do carry leading tag bytes.
+ */
+export type AccountExpectation = {
+  /** Name used in the report, e.g. the IDL account name */
+  label?: string;
+  /** Defaults to `true`. Set `false` for accounts the instruction creates. */
+  mustExist?: boolean;
+  owner?: Address;
+  dataSize?: number;
+  discriminator?: ReadonlyUint8Array;
+  minLamports?: bigint;
+  executable?: boolean;
+};
+
+export type PreflightProblem =
+  | 'missing'
+  | 'already-exists'
+  | 'owner'
+  | 'data-size'
+  | 'discriminator'
+  | 'balance'
+  | 'executable';
+
+export type PreflightIssue = {
+  address: Address;
+  label: string;
+  problem: PreflightProblem;
+  message: string;
+};
+
+export type PreflightReport = {
+  ok: boolean;
+  /** Number of distinct accounts fetched */
+  checked: number;
+  issues: PreflightIssue[];
+};
+
+export class PreflightError extends Error {
+  constructor(readonly report: PreflightReport) {
+    super(
+      [
+        `Pre-flight failed with ${report.issues.length} issue(s):`,
+        ...report.issues.map((issue) => `  ${issue.label}: ${issue.message}`),
+      ].join('\n')
+    );
+    this.name = 'PreflightError';
+  }
+}
+
+/** Compare one fetched account with its expectation; exported for tests. */
+export const checkAccount = (
+  account: MaybeEncodedAccount,
+  expectation: AccountExpectation
+): PreflightIssue[] => {
+  const label = expectation.label ?? account.address;
+  const issue = (problem: PreflightProblem, message: string) => ({
+    address: account.address,
+    label,
+    problem,
+    message,
+  });
+
+  if (expectation.mustExist === false) {
+    return account.exists && account.data.length > 0
+      ? [issue('already-exists', 'expected an uninitialized account')]
+      : [];
+  }
+  if (!account.exists) {
+    return [issue('missing', 'account does not exist')];
+  }
+
+  const issues: PreflightIssue[] = [];
+  if (expectation.owner && account.programAddress !== expectation.owner) {
+    issues.push(
+      issue(
+        'owner',
+        `owned by ${account.programAddress}, expected ${expectation.owner}`
+      )
+    );
+  }
+  if (
+    expectation.dataSize !== undefined &&
+    account.data.length !== expectation.dataSize
+  ) {
+    issues.push(
+      issue(
+        'data-size',
+        `holds ${account.data.length} bytes, expected ${expectation.dataSize}`
+      )
+    );
+  }
+  if (
+    expectation.discriminator &&
+    !expectation.discriminator.every((byte, i) => account.data[i] === byte)
+  ) {
+    issues.push(issue('discriminator', 'unexpected discriminator'));
+  }
+  if (
+    expectation.minLamports !== undefined &&
+    account.lamports < expectation.minLamports
+  ) {
+    issues.push(
+      issue(
+        'balance',
+        `has ${account.lamports} lamports, needs at least ${expectation.minLamports}`
+      )
+    );
+  }
+  if (
+    expectation.executable !== undefined &&
+    account.executable !== expectation.executable
+  ) {
+    issues.push(
+      issue(
+        'executable',
+        expectation.executable ? 'expected a program' : 'expected a non-program account'
+      )
+    );
+  }
+  return issues;
+};
+
+/** Check `expectations` against the current on-chain state of each address. */
+export const preflight = async (
+  rpc: Rpc<GetMultipleAccountsApi>,
+  expectations: Record<Address, AccountExpectation>
+): Promise<PreflightReport> => {
+  const addresses = Object.keys(expectations) as Address[];
+  const accounts = await fetchEncodedAccounts(rpc, addresses);
+  const issues = accounts.flatMap((account) =>
+    checkAccount(account, expectations[account.address])
+  );
+
+  return { ok: issues.length === 0, checked: addresses.length, issues };
+};
+
+/**
+ * Pre-flight every account referenced by `instructions`. Each program must be
+ * deployed and every other account must exist, unless `expectations` says
+ * otherwise for that address (e.g. `{ mustExist: false }` for a PDA being
+ * created, or `{ owner, dataSize }` for program state).
+ */
+export const preflightInstructions = (
+  rpc: Rpc<GetMultipleAccountsApi>,
+  instructions: readonly IInstruction[],
+  expectations: Record<Address, AccountExpectation> = {}
+): Promise<PreflightReport> => {
+  const merged: Record<Address, AccountExpectation> = {};
+  for (const ix of instructions) {
+    merged[ix.programAddress] ??= { executable: true };
+    for (const account of ix.accounts ?? []) {
+      merged[account.address] ??= {};
+    }
+  }
+  for (const [address, expectation] of Object.entries(expectations)) {
+    merged[address as Address] = {
+      ...merged[address as Address],
+      ...expectation,
+    };
+  }
+
+  return preflight(rpc, merged);
+};
+
+/** Throw a `PreflightError` listing every issue if the report is not ok. */
+export const assertPreflight = (report: PreflightReport) => {
+  if (!report.ok) {
+    throw new PreflightError(report);
+  }
+};
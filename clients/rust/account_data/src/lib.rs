@@ -0,0 +1,8 @@
+//! Rust client for the `account_data` program: instruction builders and account
+//! structs generated from `idl/account_data.json` by `npm run gen:clients:rust`.
+//! Only `src/generated/` is regenerated; this file and `Cargo.toml` are not.
+
+mod generated;
+
+pub use generated::programs::ACCOUNT_DATA_ID as ID;
+pub use generated::*;
@@ -0,0 +1,8 @@
+//! Rust client for the `counter` program: instruction builders and account
+//! structs generated from `idl/counter.json` by `npm run gen:clients:rust`.
+//! Only `src/generated/` is regenerated; this file and `Cargo.toml` are not.
+
+mod generated;
+
+pub use generated::programs::COUNTER_ID as ID;
+pub use generated::*;
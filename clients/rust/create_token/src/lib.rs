@@ -0,0 +1,8 @@
+//! Rust client for the `create_token` program: instruction builders and account
+//! structs generated from `idl/create_token.json` by `npm run gen:clients:rust`.
+//! Only `src/generated/` is regenerated; this file and `Cargo.toml` are not.
+
+mod generated;
+
+pub use generated::programs::CREATE_TOKEN_ID as ID;
+pub use generated::*;
@@ -0,0 +1,8 @@
+//! Rust client for the `staking` program: instruction builders and account
+//! structs generated from `idl/staking.json` by `npm run gen:clients:rust`.
+//! Only `src/generated/` is regenerated; this file and `Cargo.toml` are not.
+
+mod generated;
+
+pub use generated::programs::STAKING_ID as ID;
+pub use generated::*;
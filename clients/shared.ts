@@ -1,19 +1,36 @@
+import { readFileSync } from 'node:fs';
+import { homedir } from 'node:os';
+import { join } from 'node:path';
+
 import {
   address,
   createKeyPairSignerFromBytes,
   createSolanaRpc,
   createSolanaRpcSubscriptions,
   sendAndConfirmTransactionFactory,
+  type KeyPairSigner,
 } from '@solana/kit';
 
-import PAYER_PRIVATE_KEY from '/home/r/.config/solana/id.json';
 export const RPC_HOST = 'api.devnet.solana.com';
 
-export const getApi = async () => {
-  const defaultPayer = await createKeyPairSignerFromBytes(
-    new Uint8Array(PAYER_PRIVATE_KEY)
+/** CLI keypair used in send mode; override with `SOLANA_KEYPAIR`. */
+export const KEYPAIR_PATH =
+  process.env.SOLANA_KEYPAIR ?? join(homedir(), '.config/solana/id.json');
+
+const readDefaultPayer = async () =>
+  createKeyPairSignerFromBytes(
+    new Uint8Array(JSON.parse(readFileSync(KEYPAIR_PATH, 'utf8')))
   );
 
+let defaultPayer: Promise<KeyPairSigner> | undefined;
+
+/**
+ * Read the CLI keypair, once. Scripts only call this in send mode, so export
+ * mode (see `export.ts`) works on a machine without a keypair file.
+ */
+export const loadDefaultPayer = () => (defaultPayer ??= readDefaultPayer());
+
+export const getApi = async () => {
   const rpc = createSolanaRpc(`https://${RPC_HOST}`);
   const rpcSubscriptions = createSolanaRpcSubscriptions(`wss://${RPC_HOST}`);
 
@@ -32,7 +49,13 @@ export const getApi = async () => {
   });
 
   return {
-    defaultPayer,
+    /**
+     * The CLI keypair, read on first access through `loadDefaultPayer()`.
+     * Leave it alone in export mode.
+     */
+    get defaultPayer() {
+      return loadDefaultPayer();
+    },
     rpc,
     rpcSubscriptions,
     sendAndConfirmTransaction,
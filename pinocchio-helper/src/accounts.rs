@@ -0,0 +1,137 @@
+//! Instruction account lists declared once.
+//!
+//! Handlers destructure the account slice in a fixed order, and the Shank
+//! `#[account(..)]` annotations on the instruction enum describe the same order
+//! for the IDL. [`instruction_accounts!`](crate::instruction_accounts) generates
+//! the accounts struct, its `TryFrom<&[AccountInfo]>` validation and an
+//! [`AccountMeta`] table from a single declaration:
+//!
+//! ```ignore
+//! instruction_accounts! {
+//!     pub struct CloseAddressInfoAccounts {
+//!         [signer, writable, program_owned] address_info,
+//!         [writable] destination,
+//!     }
+//! }
+//! ```
+//!
+//! The macro does not emit the enum annotations: Shank parses them from
+//! source and never sees macro output, so they are still written by hand.
+//! Each program's `tests/idl_accounts.rs` compares its `ACCOUNTS` tables with
+//! the committed IDL through `test_support::idl` and, on a mismatch, prints the
+//! annotations generated from `ACCOUNTS`. CI regenerates the IDLs with
+//! `shank idl` and fails on any diff, so the annotations, the IDL and the
+//! handlers cannot drift apart unnoticed.
+//!
+//! Fewer accounts than declared fail with `NotEnoughAccountKeys`. Accounts past
+//! the declared ones are left to the handler, like the remaining accounts the
+//! generated clients append, and are not checked here.
+//!
+//! Accounts without flags are bound but not checked. Flags, each checked at the
+//! account's position with [`checks`](crate::checks):
+//!
+//! - `signer`: [`require_signer`](crate::checks::require_signer)
+//! - `writable`: [`require_writable`](crate::checks::require_writable)
+//! - `program_owned`: [`require_owner`](crate::checks::require_owner) against `crate::ID`
+//! - `empty`: [`require_empty`](crate::checks::require_empty)
+//! - `data`: [`require_data`](crate::checks::require_data)
+//! - `executable`: [`require_executable`](crate::checks::require_executable)
+
+#[doc(hidden)]
+pub use pinocchio::account_info::AccountInfo;
+
+/// Name and IDL flags of one declared account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountMeta {
+    pub name: &'static str,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// See the [module documentation](crate::accounts).
+#[macro_export]
+macro_rules! instruction_accounts {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($([$($flag:ident),* $(,)?])? $field:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name<'info> {
+            $(pub $field: &'info $crate::accounts::AccountInfo,)*
+        }
+
+        impl $name<'_> {
+            /// Accounts in instruction order, as they must appear in the IDL
+            pub const ACCOUNTS: &'static [$crate::accounts::AccountMeta] = &[
+                $($crate::accounts::AccountMeta {
+                    name: stringify!($field),
+                    is_signer: $crate::__has_flag!(signer; $($($flag),*)?),
+                    is_writable: $crate::__has_flag!(writable; $($($flag),*)?),
+                },)*
+            ];
+        }
+
+        impl<'info> TryFrom<&'info [$crate::accounts::AccountInfo]> for $name<'info> {
+            type Error = $crate::error::ProgramError;
+
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn try_from(
+                accounts: &'info [$crate::accounts::AccountInfo],
+            ) -> Result<Self, Self::Error> {
+                let [$($field,)* ..] = accounts else {
+                    return Err($crate::error::ProgramError::NotEnoughAccountKeys);
+                };
+
+                let mut index = 0usize;
+                $(
+                    $($($crate::__check_flag!($flag, index, $field);)*)?
+                    index += 1;
+                )*
+
+                Ok(Self { $($field),* })
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __has_flag {
+    ($want:ident;) => {
+        false
+    };
+    (signer; signer $(, $rest:ident)*) => {
+        true
+    };
+    (writable; writable $(, $rest:ident)*) => {
+        true
+    };
+    ($want:ident; $other:ident $(, $rest:ident)*) => {
+        $crate::__has_flag!($want; $($rest),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_flag {
+    (signer, $index:expr, $account:expr) => {
+        $crate::checks::require_signer($index, $account)?
+    };
+    (writable, $index:expr, $account:expr) => {
+        $crate::checks::require_writable($index, $account)?
+    };
+    (program_owned, $index:expr, $account:expr) => {
+        $crate::checks::require_owner($index, $account, &crate::ID)?
+    };
+    (empty, $index:expr, $account:expr) => {
+        $crate::checks::require_empty($index, $account)?
+    };
+    (data, $index:expr, $account:expr) => {
+        $crate::checks::require_data($index, $account)?
+    };
+    (executable, $index:expr, $account:expr) => {
+        $crate::checks::require_executable($index, $account)?
+    };
+}
@@ -0,0 +1,128 @@
+//! Account validation helpers.
+//!
+//! Every check takes the account's position in the instruction's account list.
+//! With the `verbose` feature enabled a failing check logs that index, the name
+//! of the check and, where it applies, the expected and actual pubkeys before
+//! returning the error. Without the feature the reporting compiles away.
+
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+#[inline(always)]
+pub fn require_signer(index: usize, account: &AccountInfo) -> ProgramResult {
+    if account.is_signer() {
+        return Ok(());
+    }
+    report(index, "signer");
+    Err(ProgramError::MissingRequiredSignature)
+}
+
+#[inline(always)]
+pub fn require_writable(index: usize, account: &AccountInfo) -> ProgramResult {
+    if account.is_writable() {
+        return Ok(());
+    }
+    report(index, "writable");
+    Err(ProgramError::InvalidAccountData)
+}
+
+#[inline(always)]
+pub fn require_owner(index: usize, account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.is_owned_by(owner) {
+        return Ok(());
+    }
+    report_keys(index, "owner", owner, unsafe { account.owner() });
+    Err(ProgramError::InvalidAccountOwner)
+}
+
+/// Fails with `InvalidAccountData` when the account is not `expected`.
+#[inline(always)]
+pub fn require_key(index: usize, account: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if account.key() == expected {
+        return Ok(());
+    }
+    report_keys(index, "key", expected, account.key());
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// Same as [`require_key`] for a derived address, failing with `InvalidSeeds`.
+#[inline(always)]
+pub fn require_pda(index: usize, account: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if account.key() == expected {
+        return Ok(());
+    }
+    report_keys(index, "pda", expected, account.key());
+    Err(ProgramError::InvalidSeeds)
+}
+
+/// The account must not hold any data yet.
+#[inline(always)]
+pub fn require_empty(index: usize, account: &AccountInfo) -> ProgramResult {
+    if account.data_len() == 0 {
+        return Ok(());
+    }
+    report(index, "empty");
+    Err(ProgramError::AccountAlreadyInitialized)
+}
+
+/// The account must already hold data.
+#[inline(always)]
+pub fn require_data(index: usize, account: &AccountInfo) -> ProgramResult {
+    if account.data_len() != 0 {
+        return Ok(());
+    }
+    report(index, "data");
+    Err(ProgramError::InvalidAccountData)
+}
+
+/// The account must be `wallet`'s canonical associated token account for
+/// `mint`, failing with `InvalidSeeds`. Derivation costs a `find_program_address`.
+#[inline(always)]
+pub fn require_associated_token_account(
+    index: usize,
+    account: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> ProgramResult {
+    let (expected, _) = crate::token::find_associated_token_address(wallet, mint, token_program);
+    if account.key() == &expected {
+        return Ok(());
+    }
+    report_keys(index, "associated token account", &expected, account.key());
+    Err(ProgramError::InvalidSeeds)
+}
+
+#[inline(always)]
+pub fn require_executable(index: usize, account: &AccountInfo) -> ProgramResult {
+    if account.executable() {
+        return Ok(());
+    }
+    report(index, "executable");
+    Err(ProgramError::IncorrectProgramId)
+}
+
+#[cfg(feature = "verbose")]
+#[cold]
+fn report(index: usize, check: &str) {
+    pinocchio_log::log!("account[{}] failed check: {}", index, check);
+}
+
+#[cfg(not(feature = "verbose"))]
+#[inline(always)]
+fn report(_index: usize, _check: &str) {}
+
+#[cfg(feature = "verbose")]
+#[cold]
+fn report_keys(index: usize, check: &str, expected: &Pubkey, actual: &Pubkey) {
+    report(index, check);
+    pinocchio_log::log!("expected:");
+    pinocchio::pubkey::log(expected);
+    pinocchio_log::log!("actual:");
+    pinocchio::pubkey::log(actual);
+}
+
+#[cfg(not(feature = "verbose"))]
+#[inline(always)]
+fn report_keys(_index: usize, _check: &str, _expected: &Pubkey, _actual: &Pubkey) {}
@@ -0,0 +1,110 @@
+//! System program CPIs that report which call failed.
+//!
+//! With the `verbose` feature a failing CPI logs its name and the returned
+//! error code before propagating it, matching the reporting in [`checks`].
+//!
+//! [`checks`]: crate::checks
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
+
+/// Create a rent-exempt account owned by `owner` for a fresh keypair signer.
+pub fn create_account(
+    payer: &AccountInfo,
+    new_account: &AccountInfo,
+    space: usize,
+    owner: &Pubkey,
+) -> ProgramResult {
+    let result = CreateAccount {
+        from: payer,
+        to: new_account,
+        lamports: Rent::get()?.minimum_balance(space),
+        space: space as u64,
+        owner,
+    }
+    .invoke();
+
+    checked("create_account", result)
+}
+
+/// Create a PDA account for the given seeds.
+pub fn create_pda_account(
+    payer: &AccountInfo,
+    new_pda_account: &AccountInfo,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[Seed],
+) -> ProgramResult {
+    let signers = [Signer::from(signer_seeds)];
+    let minimum_balance = Rent::get()?.minimum_balance(space).max(1);
+
+    if new_pda_account.lamports() > 0 {
+        // Anyone can transfer lamports to accounts before they're initialized
+        // in that case, creating the account won't work.
+        // in order to get around it, you need to fund the account with enough lamports to be rent exempt,
+        // then allocate the required space and set the owner to the current program
+        let required_lamports = minimum_balance.saturating_sub(new_pda_account.lamports());
+        if required_lamports > 0 {
+            transfer(payer, new_pda_account, required_lamports)?;
+        }
+
+        let result = Allocate {
+            account: new_pda_account,
+            space: space as u64,
+        }
+        .invoke_signed(&signers);
+        checked("allocate", result)?;
+
+        let result = Assign {
+            account: new_pda_account,
+            owner,
+        }
+        .invoke_signed(&signers);
+        checked("assign", result)
+    } else {
+        let result = CreateAccount {
+            from: payer,
+            to: new_pda_account,
+            lamports: minimum_balance,
+            space: space as u64,
+            owner,
+        }
+        .invoke_signed(&signers);
+
+        checked("create_account", result)
+    }
+}
+
+/// Move lamports out of a system-owned signer.
+///
+/// Use [`lamports::move_lamports`](crate::lamports::move_lamports) instead when
+/// `from` is owned by the calling program.
+pub fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    let result = Transfer { from, to, lamports }.invoke();
+
+    checked("transfer", result)
+}
+
+#[inline(always)]
+fn checked(name: &str, result: ProgramResult) -> ProgramResult {
+    if let Err(error) = &result {
+        report(name, error);
+    }
+    result
+}
+
+#[cfg(feature = "verbose")]
+#[cold]
+fn report(name: &str, error: &pinocchio::program_error::ProgramError) {
+    pinocchio_log::log!("cpi failed: {} ({})", name, u64::from(error.clone()));
+}
+
+#[cfg(not(feature = "verbose"))]
+#[inline(always)]
+fn report(_name: &str, _error: &pinocchio::program_error::ProgramError) {}
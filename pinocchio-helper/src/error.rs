@@ -0,0 +1,63 @@
+//! Custom program errors.
+//!
+//! Every program declares its errors through [`program_error!`](crate::program_error),
+//! which keeps the codes explicit and generates the `ProgramError` conversion
+//! so handlers can write `Err(CounterError::Overflow.into())` or use `?`:
+//!
+//! ```ignore
+//! program_error! {
+//!     pub enum CounterError {
+//!         /// The count would overflow or underflow
+//!         Overflow = 6001,
+//!     }
+//! }
+//! ```
+//!
+//! Codes start at 6001 in every program so they never collide with the
+//! builtin `ProgramError` variants.
+
+#[doc(hidden)]
+pub use pinocchio::program_error::ProgramError;
+
+/// Declares a `#[repr(u32)]` error enum with explicit codes, a
+/// `From<_> for ProgramError` conversion and a `TryFrom<u32>` lookup for
+/// decoding `ProgramError::Custom` codes in tests and clients.
+#[macro_export]
+macro_rules! program_error {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $code:literal
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u32)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $code,
+            )*
+        }
+
+        impl From<$name> for $crate::error::ProgramError {
+            fn from(error: $name) -> Self {
+                $crate::error::ProgramError::Custom(error as u32)
+            }
+        }
+
+        impl TryFrom<u32> for $name {
+            type Error = $crate::error::ProgramError;
+
+            fn try_from(code: u32) -> Result<Self, Self::Error> {
+                match code {
+                    $($code => Ok(Self::$variant),)*
+                    _ => Err($crate::error::ProgramError::InvalidArgument),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,37 @@
+//! Direct lamport moves for accounts owned by the calling program.
+//!
+//! The runtime lets a program debit accounts it owns without a CPI, which is
+//! both cheaper and the only option once the account holds data.
+
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+/// Move `amount` lamports from a program-owned account to any writable account.
+pub fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> ProgramResult {
+    if from.key() == to.key() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let from_lamports = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let to_lamports = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    *from.try_borrow_mut_lamports()? = from_lamports;
+    *to.try_borrow_mut_lamports()? = to_lamports;
+
+    Ok(())
+}
+
+/// Drain a program-owned account into `destination` and close it.
+///
+/// Lamports, data length and owner are zeroed so the runtime garbage collects
+/// the account at the end of the transaction.
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    move_lamports(account, destination, account.lamports())?;
+
+    account.close()
+}
@@ -0,0 +1,101 @@
+//! Little-endian integers for Pod account and instruction structs.
+//!
+//! A plain `u64` field would give a `#[repr(C)]` struct alignment padding, so
+//! state structs store integers as byte arrays. These wrappers keep the same
+//! unaligned layout while making the width part of the type, so a field can't
+//! be read back with the wrong `from_le_bytes`:
+//!
+//! ```ignore
+//! pool.total_staked = pool
+//!     .total_staked
+//!     .checked_add(amount)
+//!     .ok_or(StakingError::Overflow)?;
+//! let slot: u64 = pool.last_update_slot.get();
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+
+macro_rules! le_int {
+    ($(#[$meta:meta])* $name:ident, $int:ty, $size:literal) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Pod, Zeroable)]
+        pub struct $name([u8; $size]);
+
+        impl $name {
+            pub const ZERO: Self = Self([0; $size]);
+
+            #[inline(always)]
+            pub const fn new(value: $int) -> Self {
+                Self(value.to_le_bytes())
+            }
+
+            #[inline(always)]
+            pub const fn get(&self) -> $int {
+                <$int>::from_le_bytes(self.0)
+            }
+
+            #[inline(always)]
+            pub fn set(&mut self, value: $int) {
+                self.0 = value.to_le_bytes();
+            }
+
+            #[inline(always)]
+            pub fn checked_add(&self, rhs: $int) -> Option<Self> {
+                self.get().checked_add(rhs).map(Self::new)
+            }
+
+            #[inline(always)]
+            pub fn checked_sub(&self, rhs: $int) -> Option<Self> {
+                self.get().checked_sub(rhs).map(Self::new)
+            }
+
+            #[inline(always)]
+            pub fn checked_mul(&self, rhs: $int) -> Option<Self> {
+                self.get().checked_mul(rhs).map(Self::new)
+            }
+
+            #[inline(always)]
+            pub fn saturating_sub(&self, rhs: $int) -> Self {
+                Self::new(self.get().saturating_sub(rhs))
+            }
+        }
+
+        impl From<$int> for $name {
+            #[inline(always)]
+            fn from(value: $int) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+le_int!(
+    /// `u16` stored as 2 little-endian bytes
+    U16LE, u16, 2
+);
+le_int!(
+    /// `u32` stored as 4 little-endian bytes
+    U32LE, u32, 4
+);
+le_int!(
+    /// `u64` stored as 8 little-endian bytes
+    U64LE, u64, 8
+);
+le_int!(
+    /// `u128` stored as 16 little-endian bytes
+    U128LE, u128, 16
+);
@@ -1,3 +1,24 @@
+//! Shared building blocks for the Pinocchio programs in this workspace:
+//!
+//! - [`checks`]: account validation with optional failure logging
+//! - [`instruction_accounts!`]: accounts structs and their checks from one declaration
+//! - [`seeds!`]: PDA signer seeds including the bump
+//! - [`cpi`]: system program CPIs that report which call failed
+//! - [`program_error!`]: custom error enums convertible into `ProgramError`
+//! - [`lamports`]: direct lamport moves and account closing
+//! - [`le`]: `U16LE`/`U32LE`/`U64LE`/`U128LE` integer fields for Pod structs
+//! - [`token`]: associated token account derivation
+#![no_std]
+
+pub mod accounts;
+pub mod checks;
+pub mod cpi;
+pub mod error;
+pub mod lamports;
+pub mod le;
+pub mod seeds;
+pub mod token;
+
 // Helper function to create padded arrays of the right size
 pub fn create_padded_array<const N: usize>(data: &[u8], size: usize) -> [u8; N] {
     let mut result = [0u8; N];
@@ -0,0 +1,30 @@
+//! PDA seed building.
+//!
+//! Signing for a PDA needs the seeds as `[Seed; N]` plus a one-byte bump array
+//! that outlives the `Signer`. [`seeds!`](crate::seeds) declares both in one
+//! statement:
+//!
+//! ```ignore
+//! seeds!(seed = [POOL_SEED, &pool.stake_mint], pool.bump);
+//! let signer = Signer::from(&seed);
+//! ```
+
+use pinocchio::instruction::Seed;
+
+/// Converts anything byte-like (`&[u8]`, `&[u8; N]`, `&Pubkey`) into a `Seed`.
+#[inline(always)]
+pub fn seed<T: AsRef<[u8]> + ?Sized>(value: &T) -> Seed<'_> {
+    Seed::from(value.as_ref())
+}
+
+/// Binds `$name` to the signer seeds for `[$seed, ..., bump]`.
+///
+/// Every seed must be a reference; the bump is a `u8` value. The bump array is
+/// declared in the caller's scope so `$name` can be borrowed by a `Signer`.
+#[macro_export]
+macro_rules! seeds {
+    ($name:ident = [$($seed:expr),* $(,)?], $bump:expr) => {
+        let bump = [$bump];
+        let $name = [$($crate::seeds::seed($seed),)* $crate::seeds::seed(&bump)];
+    };
+}
@@ -0,0 +1,25 @@
+//! Associated token account derivation.
+//!
+//! Token accounts passed by a user are only trusted as the user's account when
+//! they sit at the canonical ATA address for the wallet and mint; an arbitrary
+//! token account with the right owner and mint fields is not enough. Pair
+//! [`find_associated_token_address`] with
+//! [`require_associated_token_account`](crate::checks::require_associated_token_account).
+
+use pinocchio::pubkey::{self, Pubkey};
+
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Address and bump of `wallet`'s associated token account for `mint`, where
+/// `token_program` is the program that owns the mint (Token or Token-2022).
+pub fn find_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, u8) {
+    pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+}
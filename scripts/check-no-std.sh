@@ -0,0 +1,86 @@
+#!/bin/bash
+
+# Script to audit on-chain crates for no_std compliance
+# Usage: ./check-no-std.sh [--skip-build] [crate_dir...]
+#
+# For every on-chain crate (basics/*, tokens/*, templates/* by default):
+#   1. src/lib.rs must declare #![no_std] or #![cfg_attr(not(test), no_std)]
+#   2. nothing under src/ may pull in std (std:: paths, extern crate std)
+#   3. the crate must build for sbf-solana-solana with --no-default-features
+# Exits non-zero if any crate fails, so it can gate CI.
+
+SKIP_BUILD=false
+CRATES=()
+
+for arg in "$@"; do
+    case $arg in
+        --skip-build)
+            SKIP_BUILD=true
+            ;;
+        *)
+            CRATES+=("$arg")
+            ;;
+    esac
+done
+
+# Color codes
+RED='\033[0;31m'
+GREEN='\033[0;32m'
+YELLOW='\033[1;33m'
+BLUE='\033[0;34m'
+NC='\033[0m'
+
+if [ ${#CRATES[@]} -eq 0 ]; then
+    for dir in basics/* tokens/* templates/*; do
+        if [ -f "$dir/Cargo.toml" ] && [ -f "$dir/src/lib.rs" ]; then
+            CRATES+=("$dir")
+        fi
+    done
+fi
+
+FAILED=()
+
+for crate in "${CRATES[@]}"; do
+    echo -e "${BLUE}Checking $crate...${NC}"
+    crate_ok=true
+
+    if ! grep -qE '^#!\[(no_std|cfg_attr\(not\(test\), *no_std\))\]' "$crate/src/lib.rs"; then
+        echo -e "${RED}  ✗ src/lib.rs does not declare #![no_std]${NC}"
+        crate_ok=false
+    fi
+
+    # Any std path (use std::, inline std::..., ::std::) or extern crate std,
+    # ignoring line comments and doc comments
+    leaks=$(grep -rnE '(^|[^[:alnum:]_])std::|extern[[:space:]]+crate[[:space:]]+std' \
+        "$crate/src" --include=*.rs 2>/dev/null | grep -vE '^[^:]+:[0-9]+:[[:space:]]*//')
+    if [ -n "$leaks" ]; then
+        echo -e "${RED}  ✗ std used in on-chain sources:${NC}"
+        echo "$leaks" | sed 's/^/      /'
+        crate_ok=false
+    fi
+
+    if [ "$SKIP_BUILD" = false ]; then
+        if ! cargo build-sbf --manifest-path "$crate/Cargo.toml" --no-default-features > /dev/null 2>&1; then
+            echo -e "${RED}  ✗ cargo build-sbf --no-default-features failed${NC}"
+            crate_ok=false
+        fi
+    fi
+
+    if [ "$crate_ok" = true ]; then
+        echo -e "${GREEN}  ✓ no_std compliant${NC}"
+    else
+        FAILED+=("$crate")
+    fi
+done
+
+echo ""
+if [ ${#FAILED[@]} -ne 0 ]; then
+    echo -e "${RED}no_std audit failed for: ${FAILED[*]}${NC}"
+    exit 1
+fi
+
+if [ "$SKIP_BUILD" = true ]; then
+    echo -e "${YELLOW}Source checks passed (sbf build skipped)${NC}"
+else
+    echo -e "${GREEN}All ${#CRATES[@]} on-chain crates are no_std compliant${NC}"
+fi
@@ -9,70 +9,168 @@ import { dirname } from "path";
 const __filename = fileURLToPath(import.meta.url);
 const __dirname = dirname(__filename);
 
-// Get program name from command line argument
-const programName = process.argv[2];
-if (!programName) {
-  console.error("Usage: node generate-clients.js <program-name>");
+// Usage: node generate-clients.js <program-name|--all> [--rust] [--js]
+// Defaults to the TypeScript client only, matching the gen:client:* scripts.
+const args = process.argv.slice(2);
+const flags = new Set(args.filter((arg) => arg.startsWith("--")));
+const programName = args.find((arg) => !arg.startsWith("--"));
+if (!programName && !flags.has("--all")) {
+  console.error("Usage: node generate-clients.js <program-name|--all> [--rust] [--js]");
   process.exit(1);
 }
 
-console.log(`Generating TypeScript client for ${programName}...`);
+const renderRust = flags.has("--rust");
+const renderJs = flags.has("--js") || !renderRust;
 
 const projectRoot = path.join(__dirname, "..");
 const idlDir = path.join(projectRoot, "idl");
-const idlFile = path.join(idlDir, `${programName.replace(/-/g, "_")}.json`);
 
-// Check if IDL file exists
-if (!fs.existsSync(idlFile)) {
-  console.error(`IDL file not found: ${idlFile}`);
-  console.error("Run the IDL generation first:");
-  console.error(`npm run gen:idl:${programName}`);
-  process.exit(1);
-}
+// Every IDL emitted by `shank idl` when --all is passed, otherwise just the one requested
+const idlFiles = flags.has("--all")
+  ? fs.readdirSync(idlDir).filter((file) => file.endsWith(".json")).map((file) => path.join(idlDir, file))
+  : [path.join(idlDir, `${programName.replace(/-/g, "_")}.json`)];
 
-const idl = JSON.parse(fs.readFileSync(idlFile, 'utf8'));
-
-// Convert underscores to camelCase for client directory
-const clientDirName = programName.replace(/-/g, "").replace(/_([a-z])/g, (match, letter) => letter.toUpperCase());
-const jsClientsDir = path.join(projectRoot, "clients", clientDirName);
-
-console.log(`Reading IDL from: ${idlFile}`);
-console.log(`Generating client to: ${jsClientsDir}`);
-
-const codamaInstance = codama.createFromRoot(anchorIdl.rootNodeFromAnchor(idl));
-
-// Generate TypeScript client
-codamaInstance.accept(
-  renderers.renderJavaScriptVisitor(jsClientsDir, {
-    formatCode: true,
-    deleteFolderBeforeRendering: true,
-    prettierOptions: {
-      parser: 'typescript',
-      singleQuote: true,
-      trailingComma: 'all',
-      printWidth: 80,
-    },
-  })
+// Rust clients are workspace members (clients/rust/*), so only programs that
+// are themselves in the workspace get one; stale IDLs without a crate are skipped
+const workspacePrograms = new Set(
+  ["basics", "tokens"].flatMap((group) =>
+    fs.readdirSync(path.join(projectRoot, group))
+      .map((dir) => path.join(projectRoot, group, dir, "Cargo.toml"))
+      .filter((manifest) => fs.existsSync(manifest))
+      .map((manifest) => fs.readFileSync(manifest, "utf8").match(/^name = "(.+)"/m)[1].replace(/-/g, "_"))
+  )
 );
 
-console.log(`✅ TypeScript client generated successfully!`);
-console.log(`📁 Client location: ${jsClientsDir}`);
-
-// List generated files
-if (fs.existsSync(jsClientsDir)) {
-  console.log("\n📄 Generated files:");
-  const walkDir = (dir, prefix = "") => {
-    const files = fs.readdirSync(dir, { withFileTypes: true });
-    files.forEach(file => {
-      const fullPath = path.join(dir, file.name);
-      const relativePath = path.relative(jsClientsDir, fullPath);
-      if (file.isDirectory()) {
-        console.log(`${prefix}📁 ${relativePath}/`);
-        walkDir(fullPath, prefix + "  ");
-      } else {
-        console.log(`${prefix}📄 ${relativePath}`);
-      }
-    });
-  };
-  walkDir(jsClientsDir);
+for (const idlFile of idlFiles) {
+  generate(idlFile);
+}
+
+function generate(idlFile) {
+  const name = path.basename(idlFile, ".json");
+
+  // Check if IDL file exists
+  if (!fs.existsSync(idlFile)) {
+    console.error(`IDL file not found: ${idlFile}`);
+    console.error("Run the IDL generation first:");
+    console.error(`npm run gen:idl:${name.replace(/_/g, "-")}`);
+    process.exit(1);
+  }
+
+  const idl = JSON.parse(fs.readFileSync(idlFile, 'utf8'));
+
+  // Client directories drop the separators: account_data -> clients/accountdata
+  const clientDirName = name.replace(/[-_]/g, "");
+  const codamaInstance = codama.createFromRoot(anchorIdl.rootNodeFromAnchor(idl));
+
+  console.log(`Reading IDL from: ${idlFile}`);
+
+  if (renderJs) {
+    const jsClientsDir = path.join(projectRoot, "clients", clientDirName);
+    console.log(`Generating TypeScript client to: ${jsClientsDir}`);
+
+    // Generate TypeScript client
+    codamaInstance.accept(
+      renderers.renderJavaScriptVisitor(jsClientsDir, {
+        formatCode: true,
+        deleteFolderBeforeRendering: true,
+        prettierOptions: {
+          parser: 'typescript',
+          singleQuote: true,
+          trailingComma: 'all',
+          printWidth: 80,
+        },
+      })
+    );
+
+    console.log(`✅ TypeScript client generated successfully!`);
+    listFiles(jsClientsDir);
+  }
+
+  if (renderRust && !workspacePrograms.has(name)) {
+    console.log(`Skipping Rust client for ${name}: no program crate in the workspace`);
+  } else if (renderRust) {
+    // Instruction builders and account structs go to src/generated/, which is
+    // replaced on every run; Cargo.toml and src/lib.rs are written once
+    const crateDir = path.join(projectRoot, "clients", "rust", name);
+    const rustClientsDir = path.join(crateDir, "src", "generated");
+    writeRustCrate(crateDir, name);
+    console.log(`Generating Rust client to: ${rustClientsDir}`);
+
+    codamaInstance.accept(
+      renderers.renderRustVisitor(rustClientsDir, {
+        crateFolder: crateDir,
+        formatCode: true,
+        deleteFolderBeforeRendering: true,
+      })
+    );
+
+    console.log(`✅ Rust client generated successfully!`);
+    listFiles(rustClientsDir);
+  }
+}
+
+// Scaffold the client crate (a member of the workspace via clients/rust/*)
+// the first time it is generated; existing files are left untouched
+function writeRustCrate(crateDir, name) {
+  const manifest = path.join(crateDir, "Cargo.toml");
+  if (!fs.existsSync(manifest)) {
+    fs.mkdirSync(crateDir, { recursive: true });
+    fs.writeFileSync(
+      manifest,
+      [
+        "[package]",
+        `name = "${name.replace(/_/g, "-")}-client"`,
+        'version = "0.1.0"',
+        "edition.workspace = true",
+        "license.workspace = true",
+        "",
+        "[dependencies]",
+        "borsh = { workspace = true }",
+        "solana-program = { workspace = true }",
+        "",
+      ].join("\n")
+    );
+  }
+
+  const lib = path.join(crateDir, "src", "lib.rs");
+  if (!fs.existsSync(lib)) {
+    fs.mkdirSync(path.dirname(lib), { recursive: true });
+    fs.writeFileSync(
+      lib,
+      [
+        `//! Rust client for the \`${name}\` program: instruction builders and account`,
+        `//! structs generated from \`idl/${name}.json\` by \`npm run gen:clients:rust\`.`,
+        "//! Only `src/generated/` is regenerated; this file and `Cargo.toml` are not.",
+        "",
+        "mod generated;",
+        "",
+        `pub use generated::programs::${name.toUpperCase()}_ID as ID;`,
+        "pub use generated::*;",
+        "",
+      ].join("\n")
+    );
+  }
+}
+
+function listFiles(clientsDir) {
+  console.log(`📁 Client location: ${clientsDir}`);
+
+  // List generated files
+  if (fs.existsSync(clientsDir)) {
+    console.log("\n📄 Generated files:");
+    const walkDir = (dir, prefix = "") => {
+      const files = fs.readdirSync(dir, { withFileTypes: true });
+      files.forEach(file => {
+        const fullPath = path.join(dir, file.name);
+        const relativePath = path.relative(clientsDir, fullPath);
+        if (file.isDirectory()) {
+          console.log(`${prefix}📁 ${relativePath}/`);
+          walkDir(fullPath, prefix + "  ");
+        } else {
+          console.log(`${prefix}📄 ${relativePath}`);
+        }
+      });
+    };
+    walkDir(clientsDir);
+  }
 }
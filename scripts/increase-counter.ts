@@ -0,0 +1,101 @@
+/**
+ * Increase the counter.
+ *
+ *   bun scripts/increase-counter.ts
+ *   CLIENT_MODE=export AUTHORITY=<address> bun scripts/increase-counter.ts
+ *
+ * Every account is pre-flighted first. In send mode the CLI keypair signs as
+ * the authority. In export mode the keypair is never read: the unsigned
+ * transaction is written to `EXPORT_PATH` (default `increase-counter.json`)
+ * for the authority to sign in a wallet or multisig.
+ */
+import {
+  address,
+  appendTransactionMessageInstructions,
+  createNoopSigner,
+  createTransactionMessage,
+  getProgramDerivedAddress,
+  getSignatureFromTransaction,
+  pipe,
+  setTransactionMessageFeePayerSigner,
+  setTransactionMessageLifetimeUsingBlockhash,
+  signTransactionMessageWithSigners,
+  type TransactionSigner,
+} from '@solana/kit';
+
+import {
+  COUNTER_PROGRAM_ADDRESS,
+  getIncreaseInstruction,
+} from '../clients/counter';
+import {
+  exportTransaction,
+  getClientMode,
+  writeExportedTransaction,
+} from '../clients/export';
+import { assertPreflight, preflightInstructions } from '../clients/preflight';
+import { explorerUrl, getApi, loadDefaultPayer } from '../clients/shared';
+
+const mode = getClientMode();
+const { rpc, sendAndConfirmTransaction } = await getApi();
+
+let authority: TransactionSigner;
+if (mode === 'export') {
+  if (!process.env.AUTHORITY) {
+    throw new Error('Set AUTHORITY to the address that will sign the export');
+  }
+  authority = createNoopSigner(address(process.env.AUTHORITY));
+} else {
+  authority = await loadDefaultPayer();
+}
+
+const [counter] = await getProgramDerivedAddress({
+  programAddress: COUNTER_PROGRAM_ADDRESS,
+  seeds: ['counter'],
+});
+const instructions = [
+  getIncreaseInstruction({
+    authority,
+    counter,
+    step: 1,
+    expectedCount: 0,
+    expirySlot: 0,
+    flags: 0,
+  }),
+];
+
+// Fail locally on a missing or foreign counter instead of an opaque program
+// error, in both modes: an export that cannot land is not worth signing.
+assertPreflight(
+  await preflightInstructions(rpc, instructions, {
+    [counter]: {
+      label: 'counter',
+      owner: COUNTER_PROGRAM_ADDRESS,
+      dataSize: 8,
+    },
+    [authority.address]: { label: 'authority', minLamports: 5_000n },
+  })
+);
+
+if (mode === 'export') {
+  const exported = await exportTransaction({
+    rpc,
+    feePayer: authority.address,
+    instructions,
+    programNames: { [COUNTER_PROGRAM_ADDRESS]: 'counter' },
+  });
+  const path = process.env.EXPORT_PATH ?? 'increase-counter.json';
+  writeExportedTransaction(path, exported);
+  console.log(exported.summary.join('\n'));
+  console.log(`Unsigned transaction written to ${path}`);
+} else {
+  const { value: latestBlockhash } = await rpc.getLatestBlockhash().send();
+  const message = pipe(
+    createTransactionMessage({ version: 0 }),
+    (m) => setTransactionMessageFeePayerSigner(authority, m),
+    (m) => setTransactionMessageLifetimeUsingBlockhash(latestBlockhash, m),
+    (m) => appendTransactionMessageInstructions(instructions, m)
+  );
+  const signed = await signTransactionMessageWithSigners(message);
+  await sendAndConfirmTransaction(signed, { commitment: 'confirmed' });
+  console.log(explorerUrl(getSignatureFromTransaction(signed)));
+}
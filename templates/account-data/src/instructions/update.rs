@@ -0,0 +1,37 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use crate::state::{AddressInfo, UpdateAddressInfoAccounts, UpdateAddressInfoInstructionData};
+
+pub struct Update<'info> {
+    pub accounts: UpdateAddressInfoAccounts<'info>,
+    pub instruction_data: UpdateAddressInfoInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], UpdateAddressInfoInstructionData)> for Update<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, instruction_data): (&'info [AccountInfo], UpdateAddressInfoInstructionData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = UpdateAddressInfoAccounts::try_from(accounts)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'info> Update<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let address_info_state = unsafe {
+            bytemuck::try_from_bytes_mut::<AddressInfo>(
+                self.accounts.address_info.borrow_mut_data_unchecked(),
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        };
+
+        self.instruction_data.apply(address_info_state);
+
+        Ok(())
+    }
+}
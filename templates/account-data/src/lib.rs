@@ -1,13 +1,15 @@
-use crate::processor::process_instruction;
-use pinocchio::program_entrypoint;
+#![no_std]
+#![allow(unexpected_cfgs)]
+
 use pinocchio_pubkey::declare_id;
 
 pub mod constants;
-pub mod entrypoint;
+pub mod error;
 pub mod instructions;
 pub mod processor;
 pub mod state;
 
-declare_id!("8mqZdKKFP1rLWGJk8BtwV88t5YHHfF8v5rQbL9cEqrQx");
+#[cfg(not(feature = "no-entrypoint"))]
+pub mod entrypoint;
 
-program_entrypoint!(process_instruction);
+declare_id!("8mqZdKKFP1rLWGJk8BtwV88t5YHHfF8v5rQbL9cEqrQx");
@@ -57,15 +57,15 @@ mod tests {
         /// Create a new Mollusk instance for testing
         ///
         /// # Arguments
-        /// * `program_path` - Path to the compiled program binary (relative to target/deploy/)
+        /// * `program_name` - Name of the compiled program binary, resolved by
+        ///   `test_support::program_path` (`$SBF_OUT_DIR` or the workspace's target/deploy/)
         ///
         /// # Example
         /// ```rust
         /// let mollusk = create_mollusk("my_program");
         /// ```
-        pub fn create_mollusk(program_path: &str) -> Mollusk {
-            let full_path = format!("../../target/deploy/{}", program_path);
-            Mollusk::new(&PROGRAM_ID, &full_path)
+        pub fn create_mollusk(program_name: &str) -> Mollusk {
+            test_support::mollusk(&PROGRAM_ID, program_name)
         }
 
         /// Create a funded user account
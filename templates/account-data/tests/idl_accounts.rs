@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use account_data_template::state::{
+        CloseAddressInfoAccounts, CreateAddressInfoAccounts, UpdateAddressInfoAccounts,
+    };
+    use test_support::idl::assert_accounts_match;
+
+    const IDL: &str = include_str!("../../../idl/account_data.json");
+
+    #[test]
+    fn test_create_accounts_match_idl() {
+        assert_accounts_match(IDL, "Create", CreateAddressInfoAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_update_accounts_match_idl() {
+        assert_accounts_match(
+            IDL,
+            "UpdateAddressInfo",
+            UpdateAddressInfoAccounts::ACCOUNTS,
+        );
+    }
+
+    #[test]
+    fn test_close_accounts_match_idl() {
+        assert_accounts_match(IDL, "CloseAddressInfo", CloseAddressInfoAccounts::ACCOUNTS);
+    }
+}
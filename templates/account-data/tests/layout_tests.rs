@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod tests {
+    use account_data_template::state::{
+        AddressInfo, CreateAddressInfoInstructionData, UpdateAddressInfoInstructionData,
+    };
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_address_info_layout() {
+        assert_layout!(AddressInfo, size = 151, {
+            name => 0,
+            house_number => 50,
+            street => 51,
+            city => 101,
+        });
+    }
+
+    #[test]
+    fn test_create_address_info_instruction_data_layout() {
+        assert_layout!(CreateAddressInfoInstructionData, size = 151, {
+            name => 0,
+            house_number => 50,
+            street => 51,
+            city => 101,
+        });
+    }
+
+    #[test]
+    fn test_update_address_info_instruction_data_layout() {
+        assert_layout!(UpdateAddressInfoInstructionData, size = 152, {
+            fields => 0,
+            name => 1,
+            house_number => 51,
+            street => 52,
+            city => 102,
+        });
+    }
+}
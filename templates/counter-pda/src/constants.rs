@@ -0,0 +1,4 @@
+pub const COUNTER_SEED: &[u8] = b"counter";
+
+/// First byte of every initialized `Counter` account.
+pub const COUNTER_DISCRIMINATOR: u8 = 1;
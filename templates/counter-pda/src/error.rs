@@ -0,0 +1,13 @@
+use pinocchio_helper::program_error;
+
+program_error! {
+    /// Errors returned by the counter program
+    pub enum CounterError {
+        /// The count would overflow or underflow
+        Overflow = 6001,
+        /// The signer is not the counter's authority
+        InvalidAuthority = 6002,
+        /// The counter account was never created by this program
+        UninitializedCounter = 6003,
+    }
+}
@@ -0,0 +1,34 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+use pinocchio_helper::le::U64LE;
+
+use crate::state::MutationType;
+
+/// Emitted through `sol_log_data` after every increase/decrease so indexers
+/// can follow a counter without fetching the account.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CounterEvent {
+    /// `MutationType` discriminant (0 = increase, 1 = decrease)
+    pub mutation: u8,
+    pub authority: Pubkey,
+    pub previous: U64LE,
+    pub current: U64LE,
+}
+
+impl CounterEvent {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    pub fn new(mutation: MutationType, authority: &Pubkey, previous: u64, current: u64) -> Self {
+        Self {
+            mutation: mutation as u8,
+            authority: *authority,
+            previous: U64LE::new(previous),
+            current: U64LE::new(current),
+        }
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[bytemuck::bytes_of(self)]);
+    }
+}
@@ -0,0 +1,99 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey,
+    ProgramResult,
+};
+use pinocchio_helper::{
+    checks::require_pda,
+    cpi::create_pda_account,
+    instruction_accounts,
+    le::U64LE,
+    seeds,
+};
+
+use crate::{constants::COUNTER_SEED, state::Counter};
+
+instruction_accounts! {
+    pub struct CreateCounterIxAccounts {
+        [signer, writable] authority,
+        [writable, empty] counter,
+        system_program,
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CreateCounterInstructionData {
+    pub initial_value: U64LE,
+    pub bump: u8,
+}
+
+impl CreateCounterInstructionData {
+    pub const LEN: usize = core::mem::size_of::<CreateCounterInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for CreateCounterInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct Create<'info> {
+    pub accounts: CreateCounterIxAccounts<'info>,
+    pub data: CreateCounterInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Create<'info> {
+    type Error = ProgramError;
+
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = CreateCounterIxAccounts::try_from(accounts)?;
+        let data = CreateCounterInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> Create<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        // Only the canonical bump is accepted, so each authority has exactly one counter
+        let (counter_pubkey, bump) = pubkey::find_program_address(
+            &[COUNTER_SEED, self.accounts.authority.key().as_ref()],
+            &crate::ID,
+        );
+        if self.data.bump != bump {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        require_pda(1, self.accounts.counter, &counter_pubkey)?;
+
+        seeds!(seed = [COUNTER_SEED, self.accounts.authority.key()], self.data.bump);
+        create_pda_account(
+            self.accounts.authority,
+            self.accounts.counter,
+            Counter::LEN,
+            &crate::ID,
+            &seed,
+        )?;
+
+        let counter = unsafe {
+            bytemuck::try_from_bytes_mut::<Counter>(
+                self.accounts.counter.borrow_mut_data_unchecked(),
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        };
+
+        counter.set_inner(Counter::new(
+            *self.accounts.authority.key(),
+            self.data.bump,
+            self.data.initial_value.get(),
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey, ProgramResult};
+use pinocchio_helper::{checks::require_pda, instruction_accounts};
+
+use crate::{
+    constants::COUNTER_SEED,
+    error::CounterError,
+    events::CounterEvent,
+    state::{Counter, MutationType},
+};
+
+instruction_accounts! {
+    pub struct MutateCounterIxsAccounts {
+        [signer] authority,
+        [writable, program_owned] counter,
+    }
+}
+
+pub struct Mutate<'info> {
+    pub accounts: MutateCounterIxsAccounts<'info>,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for Mutate<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = MutateCounterIxsAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> Mutate<'info> {
+    pub fn handler(&mut self, action: MutationType) -> ProgramResult {
+        let counter = unsafe {
+            bytemuck::try_from_bytes_mut::<Counter>(
+                self.accounts.counter.borrow_mut_data_unchecked(),
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        };
+
+        if !counter.is_initialized() {
+            return Err(CounterError::UninitializedCounter.into());
+        }
+
+        if counter.authority.ne(self.accounts.authority.key()) {
+            return Err(CounterError::InvalidAuthority.into());
+        }
+
+        // The stored bump lets us re-derive with create_program_address instead
+        // of paying for find_program_address on every mutation.
+        let counter_pubkey = pubkey::create_program_address(
+            &[COUNTER_SEED, counter.authority.as_ref(), &[counter.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| ProgramError::InvalidSeeds)?;
+
+        require_pda(1, self.accounts.counter, &counter_pubkey)?;
+
+        let previous = counter.count;
+        counter.count = match action {
+            MutationType::INCREASE => previous.checked_add(1),
+            MutationType::DECREASE => previous.checked_sub(1),
+        }
+        .ok_or(CounterError::Overflow)?;
+
+        CounterEvent::new(action, &counter.authority, previous.get(), counter.count.get()).emit();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+#![no_std]
+#![allow(unexpected_cfgs)]
+
+#[cfg(not(feature = "no-entrypoint"))]
+use pinocchio::program_entrypoint;
+
+#[cfg(not(feature = "no-entrypoint"))]
+use crate::processor::process_instruction;
+
+pub mod constants;
+pub mod error;
+pub mod events;
+pub mod instructions;
+pub mod processor;
+pub mod state;
+
+pinocchio_pubkey::declare_id!("FYaRcFiuojDMegn4rBH2jduRgYxb12PYhLQq762vpisF");
+
+#[cfg(not(feature = "no-entrypoint"))]
+program_entrypoint!(process_instruction);
@@ -0,0 +1,66 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_helper::le::U64LE;
+use shank::ShankAccount;
+
+use crate::constants::COUNTER_DISCRIMINATOR;
+
+/// Counter stored at the PDA `[COUNTER_SEED, authority]`.
+#[derive(ShankAccount)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Counter {
+    /// Always `COUNTER_DISCRIMINATOR` once initialized
+    pub discriminator: u8,
+    /// Canonical bump of the counter PDA
+    pub bump: u8,
+    /// The only signer allowed to mutate this counter
+    pub authority: Pubkey,
+    #[idl_type("u64")]
+    pub count: U64LE,
+}
+
+impl Counter {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    pub fn new(authority: Pubkey, bump: u8, count: u64) -> Self {
+        Self {
+            discriminator: COUNTER_DISCRIMINATOR,
+            bump,
+            authority,
+            count: U64LE::new(count),
+        }
+    }
+
+    pub fn set_inner(&mut self, data: Self) -> Self {
+        self.discriminator = data.discriminator;
+        self.bump = data.bump;
+        self.authority = data.authority;
+        self.count = data.count;
+        *self
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.discriminator == COUNTER_DISCRIMINATOR
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum MutationType {
+    INCREASE,
+    DECREASE,
+}
+
+impl TryFrom<&[u8]> for MutationType {
+    type Error = ProgramError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        match data.first() {
+            Some(0) => Ok(MutationType::INCREASE),
+            Some(1) => Ok(MutationType::DECREASE),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
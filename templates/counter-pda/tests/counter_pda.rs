@@ -0,0 +1,224 @@
+#[cfg(test)]
+mod tests {
+    use counter_pda::{
+        constants::COUNTER_SEED, instructions::CreateCounterInstructionData, state::Counter, ID,
+    };
+    use mollusk_svm::{
+        result::{Check, ProgramResult},
+        Mollusk,
+    };
+    use pinocchio_helper::le::U64LE;
+    use solana_sdk::{
+        account::AccountSharedData,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
+
+    fn counter_address(authority: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[COUNTER_SEED, authority.as_ref()], &PROGRAM_ID)
+    }
+
+    fn initialized_counter(mollusk: &Mollusk, authority: &Pubkey, count: u64) -> AccountSharedData {
+        let (_, bump) = counter_address(authority);
+        let mut counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(Counter::LEN),
+            Counter::LEN,
+            &PROGRAM_ID,
+        );
+        counter_account.set_data_from_slice(bytemuck::bytes_of(&Counter::new(
+            authority.to_bytes(),
+            bump,
+            count,
+        )));
+        counter_account
+    }
+
+    fn mutate_instruction(discriminator: u8, authority: Pubkey, counter: Pubkey) -> Instruction {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[discriminator],
+            vec![
+                AccountMeta::new_readonly(authority, true),
+                AccountMeta::new(counter, false),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_create_counter_pda() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter_pda");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let authority = Pubkey::new_from_array([0x02; 32]);
+        let authority_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+        let (counter_pubkey, bump) = counter_address(&authority);
+        let counter_account = AccountSharedData::new(0, 0, &system_program);
+
+        let ix_data = CreateCounterInstructionData {
+            initial_value: U64LE::new(100),
+            bump,
+        };
+        let data = [vec![0], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(authority, true),
+                AccountMeta::new(counter_pubkey, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let expected = Counter::new(authority.to_bytes(), bump, 100);
+
+        let result = mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (authority, authority_account.into()),
+                (counter_pubkey, counter_account.into()),
+                (system_program, system_account),
+            ],
+            &[
+                Check::success(),
+                Check::account(&counter_pubkey).owner(&PROGRAM_ID).build(),
+                Check::account(&counter_pubkey)
+                    .data(bytemuck::bytes_of(&expected))
+                    .build(),
+            ],
+        );
+
+        assert!(result.program_result == ProgramResult::Success);
+    }
+
+    #[test]
+    fn test_create_rejects_non_canonical_bump() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter_pda");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let authority = Pubkey::new_from_array([0x02; 32]);
+        let (_, canonical_bump) = counter_address(&authority);
+        // The next lower bump that still yields an off-curve address
+        let (counter_pubkey, bump) = (0..canonical_bump)
+            .rev()
+            .find_map(|bump| {
+                Pubkey::create_program_address(
+                    &[COUNTER_SEED, authority.as_ref(), &[bump]],
+                    &PROGRAM_ID,
+                )
+                .ok()
+                .map(|address| (address, bump))
+            })
+            .unwrap();
+
+        let ix_data = CreateCounterInstructionData {
+            initial_value: U64LE::new(100),
+            bump,
+        };
+        let data = [vec![0], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(authority, true),
+                AccountMeta::new(counter_pubkey, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    authority,
+                    AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (
+                    counter_pubkey,
+                    AccountSharedData::new(0, 0, &system_program).into(),
+                ),
+                (system_program, system_account),
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+
+    #[test]
+    fn test_increase_and_decrease_counter_pda() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter_pda");
+        let authority = Pubkey::new_from_array([0x02; 32]);
+        let authority_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &Pubkey::default());
+        let (counter_pubkey, bump) = counter_address(&authority);
+
+        for (discriminator, expected) in [(1u8, 101u64), (2u8, 99u64)] {
+            mollusk.process_and_validate_instruction(
+                &mutate_instruction(discriminator, authority, counter_pubkey),
+                &[
+                    (authority, authority_account.clone().into()),
+                    (
+                        counter_pubkey,
+                        initialized_counter(&mollusk, &authority, 100).into(),
+                    ),
+                ],
+                &[
+                    Check::success(),
+                    Check::account(&counter_pubkey)
+                        .data(bytemuck::bytes_of(&Counter::new(
+                            authority.to_bytes(),
+                            bump,
+                            expected,
+                        )))
+                        .build(),
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn test_mutate_rejects_foreign_authority() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter_pda");
+        let authority = Pubkey::new_from_array([0x02; 32]);
+        let intruder = Pubkey::new_from_array([0x03; 32]);
+        let intruder_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &Pubkey::default());
+        let (counter_pubkey, _) = counter_address(&authority);
+
+        mollusk.process_and_validate_instruction(
+            &mutate_instruction(1, intruder, counter_pubkey),
+            &[
+                (intruder, intruder_account.into()),
+                (
+                    counter_pubkey,
+                    initialized_counter(&mollusk, &authority, 100).into(),
+                ),
+            ],
+            &[Check::err(ProgramError::Custom(6002))],
+        );
+    }
+
+    #[test]
+    fn test_decrease_below_zero_fails() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter_pda");
+        let authority = Pubkey::new_from_array([0x02; 32]);
+        let authority_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &Pubkey::default());
+        let (counter_pubkey, _) = counter_address(&authority);
+
+        mollusk.process_and_validate_instruction(
+            &mutate_instruction(2, authority, counter_pubkey),
+            &[
+                (authority, authority_account.into()),
+                (
+                    counter_pubkey,
+                    initialized_counter(&mollusk, &authority, 0).into(),
+                ),
+            ],
+            &[Check::err(ProgramError::Custom(6001))],
+        );
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod tests {
+    use counter_pda::instructions::{CreateCounterIxAccounts, MutateCounterIxsAccounts};
+    use test_support::idl::assert_accounts_match;
+
+    const IDL: &str = include_str!("../../../idl/counter_pda.json");
+
+    #[test]
+    fn test_create_accounts_match_idl() {
+        assert_accounts_match(IDL, "Create", CreateCounterIxAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_increase_accounts_match_idl() {
+        assert_accounts_match(IDL, "Increase", MutateCounterIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_decrease_accounts_match_idl() {
+        assert_accounts_match(IDL, "Decrease", MutateCounterIxsAccounts::ACCOUNTS);
+    }
+}
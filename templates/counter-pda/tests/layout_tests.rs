@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use counter_pda::{
+        events::CounterEvent, instructions::CreateCounterInstructionData, state::Counter,
+    };
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_counter_layout() {
+        assert_layout!(Counter, size = 42, {
+            discriminator => 0,
+            bump => 1,
+            authority => 2,
+            count => 34,
+        });
+    }
+
+    #[test]
+    fn test_counter_event_layout() {
+        assert_layout!(CounterEvent, size = 49, {
+            mutation => 0,
+            authority => 1,
+            previous => 33,
+            current => 41,
+        });
+    }
+
+    #[test]
+    fn test_create_counter_instruction_data_layout() {
+        assert_layout!(CreateCounterInstructionData, size = 9, {
+            initial_value => 0,
+            bump => 8,
+        });
+    }
+}
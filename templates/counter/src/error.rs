@@ -1,12 +1,17 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio_helper::program_error;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CounterError {
-    Overflow,
-}
-
-impl From<CounterError> for ProgramError {
-    fn from(_error: CounterError) -> Self {
-        ProgramError::Custom(6001)
+program_error! {
+    /// Errors returned by the counter program
+    pub enum CounterError {
+        /// The count would overflow or underflow
+        Overflow = 6001,
+        /// The counter no longer holds the value the mutation expected
+        StaleCount = 6002,
+        /// The mutation arrived after its expiry slot
+        Expired = 6003,
+        /// The mutation options set a flag bit the program does not know
+        UnknownFlags = 6004,
+        /// The mutation options ask for a step of zero
+        ZeroStep = 6005,
     }
 }
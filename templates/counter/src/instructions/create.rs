@@ -1,49 +1,34 @@
 use bytemuck::{Pod, Zeroable};
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
     program_error::ProgramError,
     pubkey,
-    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
+use pinocchio_helper::{
+    checks::{require_owner, require_pda},
+    cpi::create_pda_account,
+    instruction_accounts,
+    le::U64LE,
+    seeds,
+};
 
 use crate::{constants::COUNTER_SEED, state::Counter};
 
-pub struct CreateCounterIxAccounts<'info> {
-    pub maker: &'info AccountInfo,
-    pub counter: &'info AccountInfo,
-}
-
-impl<'info> TryFrom<&'info [AccountInfo]> for CreateCounterIxAccounts<'info> {
-    type Error = ProgramError;
-
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [maker, counter, _] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-
-        if !maker.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if !counter.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        // Counter account should initially be owned by system program (will be transferred during creation)
-        if !counter.is_owned_by(&pinocchio_system::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-
-        Ok(Self { maker, counter })
+instruction_accounts! {
+    pub struct CreateCounterIxAccounts {
+        [signer, writable] maker,
+        [writable] counter,
+        system_program,
     }
 }
 
+#[derive(shank::ShankType)]
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct CreateCounterInstructionData {
-    pub initial_value: [u8; 8],
+    #[idl_type("u64")]
+    pub initial_value: U64LE,
     pub bump: u8,
 }
 
@@ -66,12 +51,15 @@ pub struct Create<'info> {
     pub data: CreateCounterInstructionData,
 }
 
-impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Create<'info> {
+impl<'info> TryFrom<(&'info [AccountInfo], CreateCounterInstructionData)> for Create<'info> {
     type Error = ProgramError;
 
-    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], CreateCounterInstructionData),
+    ) -> Result<Self, Self::Error> {
         let accounts = CreateCounterIxAccounts::try_from(accounts)?;
-        let data = CreateCounterInstructionData::try_from(data)?;
+        // Counter account should initially be owned by system program (will be transferred during creation)
+        require_owner(1, accounts.counter, &pinocchio_system::ID)?;
 
         Ok(Self { accounts, data })
     }
@@ -82,22 +70,16 @@ impl<'info> Create<'info> {
         let counter_pubkey =
             pubkey::create_program_address(&[COUNTER_SEED, &[self.data.bump as u8]], &crate::ID)
                 .map_err(|_| ProgramError::InvalidSeeds)?;
-        if self.accounts.counter.key() != &counter_pubkey {
-            return Err(ProgramError::InvalidSeeds);
-        }
-
-        let bump = [self.data.bump as u8];
-        let seed = [Seed::from(COUNTER_SEED), Seed::from(&bump)];
-        let signer_seeds = Signer::from(&seed);
-
-        pinocchio_system::instructions::CreateAccount {
-            from: self.accounts.maker,
-            to: self.accounts.counter,
-            space: Counter::LEN as u64,
-            lamports: Rent::get()?.minimum_balance(Counter::LEN),
-            owner: &crate::ID,
-        }
-        .invoke_signed(&[signer_seeds])?;
+        require_pda(1, self.accounts.counter, &counter_pubkey)?;
+
+        seeds!(seed = [COUNTER_SEED], self.data.bump);
+        create_pda_account(
+            self.accounts.maker,
+            self.accounts.counter,
+            Counter::LEN,
+            &crate::ID,
+            &seed,
+        )?;
 
         let counter = unsafe {
             bytemuck::try_from_bytes_mut::<Counter>(
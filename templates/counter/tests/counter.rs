@@ -1,16 +1,22 @@
 #[cfg(test)]
 mod tests {
     use counter::{
-        constants::COUNTER_SEED, instructions::CreateCounterInstructionData, state::Counter, ID,
+        constants::COUNTER_SEED,
+        error::CounterError,
+        instructions::{CreateCounterInstructionData, MutateCounterOptions},
+        state::Counter,
+        ID,
     };
     use mollusk_svm::{
         result::{Check, ProgramResult},
         Mollusk,
     };
+    use pinocchio_helper::le::U64LE;
     use solana_sdk::{
         account::AccountSharedData,
         instruction::{AccountMeta, Instruction},
         native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError,
         pubkey::Pubkey,
     };
 
@@ -18,7 +24,7 @@ mod tests {
 
     #[test]
     fn test_create_counter_data() {
-        let mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/counter");
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
 
         let (system_program, system_account) =
             mollusk_svm::program::keyed_account_for_system_program();
@@ -32,7 +38,7 @@ mod tests {
         let counter_account = AccountSharedData::new(0, 0, &system_program);
 
         let counter_init_state = Counter {
-            count: 100u64.to_le_bytes(),
+            count: U64LE::new(100),
         };
 
         {
@@ -75,7 +81,7 @@ mod tests {
             let updated_data = result.get_account(&counter_pubkey).unwrap();
             let parsed_data = bytemuck::from_bytes::<Counter>(&updated_data.data);
 
-            assert_eq!(parsed_data.count, 100u64.to_le_bytes());
+            assert_eq!(parsed_data.count.get(), 100u64);
             assert!(updated_data.owner.eq(&PROGRAM_ID));
             assert!(result.program_result == ProgramResult::Success);
         }
@@ -83,7 +89,7 @@ mod tests {
 
     #[test]
     fn test_increase_counter_data() {
-        let mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/counter");
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
         let (system_program, system_account) =
             mollusk_svm::program::keyed_account_for_system_program();
         let owner = Pubkey::new_from_array([0x02; 32]);
@@ -92,7 +98,7 @@ mod tests {
             solana_sdk::pubkey::Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
 
         let counter_init_state = Counter {
-            count: 100u64.to_le_bytes(),
+            count: U64LE::new(100),
         };
 
         {
@@ -111,8 +117,7 @@ mod tests {
                 &data,
                 vec![
                     AccountMeta::new(owner, true),
-                    AccountMeta::new(counter_pubkey, true),
-                    AccountMeta::new_readonly(system_program, false),
+                    AccountMeta::new(counter_pubkey, false),
                 ],
             );
 
@@ -129,7 +134,7 @@ mod tests {
                         Check::account(&counter_pubkey).owner(&PROGRAM_ID).build(),
                         Check::account(&counter_pubkey)
                             .data(bytemuck::bytes_of(&Counter {
-                                count: 101u64.to_le_bytes(),
+                                count: U64LE::new(101),
                             }))
                             .build(),
                     ],
@@ -138,14 +143,14 @@ mod tests {
             let updated_data = result.get_account(&counter_pubkey).unwrap();
             let parsed_data = bytemuck::from_bytes::<Counter>(&updated_data.data);
 
-            assert_eq!(parsed_data.count, 101u64.to_le_bytes());
+            assert_eq!(parsed_data.count.get(), 101u64);
             assert!(result.program_result == ProgramResult::Success);
         }
     }
     #[test]
     fn test_decrease_counter_data() {
         // Initialize test environment
-        let mollusk = Mollusk::new(&PROGRAM_ID, "../../target/deploy/counter");
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
 
         let (system_program, system_account) =
             mollusk_svm::program::keyed_account_for_system_program();
@@ -157,7 +162,7 @@ mod tests {
             solana_sdk::pubkey::Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
 
         let counter_init_state = Counter {
-            count: 100u64.to_le_bytes(),
+            count: U64LE::new(100),
         };
 
         // DECREASE COUNTER INSTRUCTION TEST
@@ -178,8 +183,7 @@ mod tests {
                 &data,
                 vec![
                     AccountMeta::new(owner, true),
-                    AccountMeta::new(counter_pubkey, true),
-                    AccountMeta::new_readonly(system_program, false),
+                    AccountMeta::new(counter_pubkey, false),
                 ],
             );
 
@@ -197,7 +201,7 @@ mod tests {
                         // Expect counter to decrease from 100 to 99
                         Check::account(&counter_pubkey)
                             .data(bytemuck::bytes_of(&Counter {
-                                count: 99u64.to_le_bytes(),
+                                count: U64LE::new(99),
                             }))
                             .build(),
                     ],
@@ -207,8 +211,246 @@ mod tests {
             let updated_data = result.get_account(&counter_pubkey).unwrap();
             let parsed_data = bytemuck::from_bytes::<Counter>(&updated_data.data);
 
-            assert_eq!(parsed_data.count, 99u64.to_le_bytes());
+            assert_eq!(parsed_data.count.get(), 99u64);
             assert!(result.program_result == ProgramResult::Success);
         }
     }
+
+    #[test]
+    fn test_create_then_mutate_chain() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, bump) =
+            solana_sdk::pubkey::Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let create_data = CreateCounterInstructionData {
+            initial_value: U64LE::new(100),
+            bump,
+        };
+        let create = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![0], bytemuck::bytes_of(&create_data).to_vec()].concat(),
+            vec![
+                AccountMeta::new(owner, true),
+                AccountMeta::new(counter_pubkey, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+        let mutate = |discriminator: u8| {
+            Instruction::new_with_bytes(
+                PROGRAM_ID,
+                &[discriminator],
+                vec![
+                    AccountMeta::new(owner, true),
+                    AccountMeta::new(counter_pubkey, false),
+                ],
+            )
+        };
+        let (increase, decrease) = (mutate(1), mutate(2));
+
+        let count = |value: u64| {
+            Check::account(&counter_pubkey)
+                .data(bytemuck::bytes_of(&Counter {
+                    count: U64LE::new(value),
+                }))
+                .build()
+        };
+
+        // Each step runs against the accounts the previous step produced
+        let result = mollusk.process_and_validate_instruction_chain(
+            &[
+                (&create, &[Check::success(), count(100)]),
+                (&increase, &[Check::success(), count(101)]),
+                (&increase, &[Check::success(), count(102)]),
+                (&decrease, &[Check::success(), count(101)]),
+            ],
+            &[
+                (
+                    owner,
+                    AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (
+                    counter_pubkey,
+                    AccountSharedData::new(0, 0, &system_program).into(),
+                ),
+                (system_program, system_account),
+            ],
+        );
+
+        assert!(result.program_result == ProgramResult::Success);
+        assert!(result
+            .get_account(&counter_pubkey)
+            .unwrap()
+            .owner
+            .eq(&PROGRAM_ID));
+    }
+
+    /// Run Increase (1) or Decrease (2) with `options` against a counter at `count`
+    fn mutate_with_options(
+        mollusk: &Mollusk,
+        discriminator: u8,
+        count: u64,
+        options: MutateCounterOptions,
+        checks: &[Check],
+    ) {
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let owner = Pubkey::new_from_array([0x02; 32]);
+        let (counter_pubkey, _) =
+            solana_sdk::pubkey::Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let mut counter_account = AccountSharedData::new(
+            mollusk.sysvars.rent.minimum_balance(Counter::LEN),
+            Counter::LEN,
+            &PROGRAM_ID,
+        );
+        counter_account.set_data_from_slice(bytemuck::bytes_of(&Counter {
+            count: U64LE::new(count),
+        }));
+
+        let data = [vec![discriminator], bytemuck::bytes_of(&options).to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(owner, true),
+                AccountMeta::new(counter_pubkey, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    owner,
+                    AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (counter_pubkey, counter_account.into()),
+                (system_program, system_account),
+            ],
+            checks,
+        );
+    }
+
+    #[test]
+    fn test_increase_by_step_with_expected_count() {
+        let mut mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        mollusk.warp_to_slot(10);
+        let (counter_pubkey, _) =
+            solana_sdk::pubkey::Pubkey::find_program_address(&[COUNTER_SEED], &PROGRAM_ID);
+
+        let options = MutateCounterOptions {
+            step: U64LE::new(5),
+            expected_count: U64LE::new(100),
+            expiry_slot: U64LE::new(10),
+            flags: MutateCounterOptions::FLAG_EXPECTED_COUNT
+                | MutateCounterOptions::FLAG_EXPIRY_SLOT,
+        };
+
+        mutate_with_options(
+            &mollusk,
+            1,
+            100,
+            options,
+            &[
+                Check::success(),
+                Check::account(&counter_pubkey)
+                    .data(bytemuck::bytes_of(&Counter {
+                        count: U64LE::new(105),
+                    }))
+                    .build(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_decrease_rejects_stale_expected_count() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+
+        let options = MutateCounterOptions {
+            step: U64LE::new(1),
+            expected_count: U64LE::new(99),
+            expiry_slot: U64LE::new(0),
+            flags: MutateCounterOptions::FLAG_EXPECTED_COUNT,
+        };
+
+        mutate_with_options(
+            &mollusk,
+            2,
+            100,
+            options,
+            &[Check::err(ProgramError::Custom(
+                CounterError::StaleCount as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_increase_rejects_expired_transaction() {
+        let mut mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+        mollusk.warp_to_slot(11);
+
+        let options = MutateCounterOptions {
+            step: U64LE::new(1),
+            expected_count: U64LE::new(0),
+            expiry_slot: U64LE::new(10),
+            flags: MutateCounterOptions::FLAG_EXPIRY_SLOT,
+        };
+
+        mutate_with_options(
+            &mollusk,
+            1,
+            100,
+            options,
+            &[Check::err(ProgramError::Custom(
+                CounterError::Expired as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_mutate_rejects_unknown_flags() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+
+        let options = MutateCounterOptions {
+            step: U64LE::new(1),
+            expected_count: U64LE::new(0),
+            expiry_slot: U64LE::new(0),
+            flags: 1 << 2,
+        };
+
+        mutate_with_options(
+            &mollusk,
+            1,
+            100,
+            options,
+            &[Check::err(ProgramError::Custom(
+                CounterError::UnknownFlags as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_mutate_rejects_zero_step() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "counter");
+
+        let options = MutateCounterOptions {
+            step: U64LE::new(0),
+            expected_count: U64LE::new(0),
+            expiry_slot: U64LE::new(0),
+            flags: 0,
+        };
+
+        mutate_with_options(
+            &mollusk,
+            2,
+            100,
+            options,
+            &[Check::err(ProgramError::Custom(
+                CounterError::ZeroStep as u32,
+            ))],
+        );
+    }
 }
@@ -1 +1,8 @@
 pub const FAVORITES_SEED: &[u8] = b"favorites";
+pub const FAVORITES_LIST_SEED: &[u8] = b"favorites_list";
+
+/// Capacity of a `FavoritesList`
+pub const MAX_FAVORITES: usize = 16;
+
+/// Most entries a single `GetFavorites` call will log
+pub const FAVORITES_PAGE_SIZE: u8 = 8;
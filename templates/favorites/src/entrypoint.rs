@@ -4,7 +4,9 @@ use pinocchio::{
 };
 use pinocchio_log::log;
 
-use crate::processor::{create_pda::CreatePda, get_pda::GetPda, Instruction};
+use crate::processor::{
+    AddFavorite, CreatePda, GetFavorites, GetPda, Instruction, RemoveFavorite, UpdateFavorites,
+};
 
 entrypoint!(process_instruction);
 
@@ -17,12 +19,8 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let (discriminator, data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
-    match Instruction::try_from(discriminator)? {
-        Instruction::CreatePda => {
+    match Instruction::try_from(instruction_data)? {
+        Instruction::CreatePda(data) => {
             log!("Instrucction: CreatePda");
             CreatePda::try_from((accounts, data))?.handler()
         }
@@ -30,5 +28,21 @@ pub fn process_instruction(
             log!("Instrucction: GetPda");
             GetPda::try_from(accounts)?.handler()
         }
+        Instruction::UpdateFavorites(data) => {
+            log!("Instruction: UpdateFavorites");
+            UpdateFavorites::try_from((accounts, data))?.handler()
+        }
+        Instruction::AddFavorite(data) => {
+            log!("Instruction: AddFavorite");
+            AddFavorite::try_from((accounts, data))?.handler()
+        }
+        Instruction::RemoveFavorite(data) => {
+            log!("Instruction: RemoveFavorite");
+            RemoveFavorite::try_from((accounts, data))?.handler()
+        }
+        Instruction::GetFavorites(data) => {
+            log!("Instruction: GetFavorites");
+            GetFavorites::try_from((accounts, data))?.handler()
+        }
     }
 }
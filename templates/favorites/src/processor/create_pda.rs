@@ -1,48 +1,30 @@
 use bytemuck::{Pod, Zeroable};
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::Seed,
     program_error::ProgramError,
     pubkey,
-    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
+use pinocchio_helper::{
+    checks::require_key, cpi::create_pda_account, instruction_accounts, le::U64LE, seeds,
+};
 
-use crate::{constants::FAVORITES_SEED, processor::create_pda_account, state::Favorites};
-
-pub struct CreatePdaIxsAccounts<'info> {
-    pub user: &'info AccountInfo,
-    pub favorites: &'info AccountInfo,
-}
-
-impl<'info> TryFrom<&'info [AccountInfo]> for CreatePdaIxsAccounts<'info> {
-    type Error = ProgramError;
-
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [user, favorites, _] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-
-        if !user.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if favorites.is_writable() {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        if favorites.data_len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
-        }
+use crate::{constants::FAVORITES_SEED, state::Favorites};
 
-        Ok(Self { user, favorites })
+instruction_accounts! {
+    pub struct CreatePdaIxsAccounts {
+        [signer, writable] user,
+        [writable, empty] favorites,
+        system_program,
     }
 }
 
+#[derive(shank::ShankType)]
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct CreatePdaIxsData {
-    pub number: [u8; 8],
+    #[idl_type("u64")]
+    pub number: U64LE,
     pub color: [u8; 50],
     pub hobby1: [u8; 50],
     pub hobby2: [u8; 50],
@@ -71,14 +53,13 @@ pub struct CreatePda<'info> {
     pub data: CreatePdaIxsData,
 }
 
-impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreatePda<'info> {
+impl<'info> TryFrom<(&'info [AccountInfo], CreatePdaIxsData)> for CreatePda<'info> {
     type Error = ProgramError;
 
     fn try_from(
-        (accounts, data): (&'info [AccountInfo], &'info [u8]),
+        (accounts, data): (&'info [AccountInfo], CreatePdaIxsData),
     ) -> Result<Self, Self::Error> {
         let accounts = CreatePdaIxsAccounts::try_from(accounts)?;
-        let data = CreatePdaIxsData::try_from(data)?;
         Ok(Self { accounts, data })
     }
 }
@@ -95,24 +76,15 @@ impl<'info> CreatePda<'info> {
         )
         .map_err(|_| ProgramError::InvalidSeeds)?;
 
-        if self.accounts.favorites.key() != &favorites_pubkey {
-            return Err(ProgramError::InvalidAccountData);
-        }
-
-        let bump = [self.data.bump as u8];
-        let seed = [
-            Seed::from(FAVORITES_SEED),
-            Seed::from(self.accounts.user.key().as_ref()),
-            Seed::from(&bump),
-        ];
+        require_key(1, self.accounts.favorites, &favorites_pubkey)?;
 
+        seeds!(seed = [FAVORITES_SEED, self.accounts.user.key()], self.data.bump);
         create_pda_account(
             self.accounts.user,
-            &Rent::get()?,
+            self.accounts.favorites,
             Favorites::LEN,
             &crate::ID,
-            self.accounts.favorites,
-            seed,
+            &seed,
         )?;
 
         let favorites = unsafe {
@@ -0,0 +1,75 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_helper::instruction_accounts;
+use pinocchio_log::log;
+
+use crate::{constants::FAVORITES_PAGE_SIZE, processor::load_favorites_list};
+
+instruction_accounts! {
+    pub struct GetFavoritesIxsAccounts {
+        user,
+        [data, program_owned] favorites_list,
+    }
+}
+
+/// Page request; `limit` is capped at `FAVORITES_PAGE_SIZE`
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct GetFavoritesIxsData {
+    pub offset: u8,
+    pub limit: u8,
+}
+
+impl GetFavoritesIxsData {
+    pub const LEN: usize = core::mem::size_of::<GetFavoritesIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for GetFavoritesIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct GetFavorites<'info> {
+    pub accounts: GetFavoritesIxsAccounts<'info>,
+    pub data: GetFavoritesIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], GetFavoritesIxsData)> for GetFavorites<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], GetFavoritesIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = GetFavoritesIxsAccounts::try_from(accounts)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> GetFavorites<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let list = load_favorites_list(self.accounts.favorites_list, self.accounts.user)?;
+        let limit = self.data.limit.min(FAVORITES_PAGE_SIZE);
+        let page = list.page(self.data.offset, limit);
+
+        log!(
+            "User {} has {} favorites, showing {} from index {}",
+            self.accounts.user.key(),
+            list.len,
+            page.len(),
+            self.data.offset
+        );
+        for (i, item) in page.iter().enumerate() {
+            let end = item.iter().position(|&b| b == 0).unwrap_or(item.len());
+            let name = core::str::from_utf8(&item[..end]).unwrap_or("<invalid utf-8>");
+            log!("{}: {}", self.data.offset as usize + i, name);
+        }
+
+        Ok(())
+    }
+}
@@ -1,28 +1,77 @@
+pub mod add_favorite;
 pub mod create_pda;
+pub mod get_favorites;
 pub mod get_pda;
+pub mod remove_favorite;
 pub mod shared;
+pub mod update_favorites;
 
+pub use add_favorite::*;
 pub use create_pda::*;
+pub use get_favorites::*;
 pub use get_pda::*;
+pub use remove_favorite::*;
 use shank::ShankInstruction;
 pub use shared::*;
+pub use update_favorites::*;
 
 use pinocchio::program_error::ProgramError;
 
 #[repr(u8)]
 #[derive(ShankInstruction)]
 pub enum Instruction {
-    CreatePda,
+    #[account(0, writable, signer, name = "user", desc = "Owner of the favorites, pays for the PDA")]
+    #[account(1, writable, name = "favorites", desc = "Favorites PDA derived from the user")]
+    #[account(2, name = "system_program", desc = "The system program")]
+    CreatePda(CreatePdaIxsData),
+
+    #[account(0, name = "user", desc = "Owner of the favorites")]
+    #[account(1, name = "favorites", desc = "Favorites PDA derived from the user")]
     GetPda,
+
+    /// Overwrite every field of an existing favorites PDA
+    #[account(0, signer, name = "user", desc = "Owner of the favorites")]
+    #[account(1, writable, name = "favorites", desc = "Favorites PDA derived from the user")]
+    UpdateFavorites(UpdateFavoritesIxsData),
+
+    /// Append an item to the user's favorites list, creating the list on first use
+    #[account(0, writable, signer, name = "user", desc = "Owner of the list, pays for it on first use")]
+    #[account(1, writable, name = "favorites_list", desc = "Favorites list PDA derived from the user")]
+    #[account(2, name = "system_program", desc = "The system program")]
+    AddFavorite(AddFavoriteIxsData),
+
+    /// Remove the item at an index, keeping the remaining order
+    #[account(0, signer, name = "user", desc = "Owner of the list")]
+    #[account(1, writable, name = "favorites_list", desc = "Favorites list PDA derived from the user")]
+    RemoveFavorite(RemoveFavoriteIxsData),
+
+    /// Log one page of the user's favorites list
+    #[account(0, name = "user", desc = "Owner of the list")]
+    #[account(1, name = "favorites_list", desc = "Favorites list PDA derived from the user")]
+    GetFavorites(GetFavoritesIxsData),
 }
 
-impl TryFrom<&u8> for Instruction {
+/// Splits off the discriminator and parses the payload into the variant, so
+/// the arguments Shank reads from each variant are the ones handlers receive.
+impl TryFrom<&[u8]> for Instruction {
     type Error = ProgramError;
 
-    fn try_from(value: &u8) -> Result<Self, Self::Error> {
-        match *value {
-            0 => Ok(Instruction::CreatePda),
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let (discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            0 => Ok(Instruction::CreatePda(CreatePdaIxsData::try_from(data)?)),
             1 => Ok(Instruction::GetPda),
+            2 => Ok(Instruction::UpdateFavorites(
+                UpdateFavoritesIxsData::try_from(data)?,
+            )),
+            3 => Ok(Instruction::AddFavorite(AddFavoriteIxsData::try_from(data)?)),
+            4 => Ok(Instruction::RemoveFavorite(RemoveFavoriteIxsData::try_from(
+                data,
+            )?)),
+            5 => Ok(Instruction::GetFavorites(GetFavoritesIxsData::try_from(data)?)),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -0,0 +1,76 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+use shank::ShankAccount;
+
+use crate::{constants::MAX_FAVORITES, error::FavoritesError};
+
+/// Bounded, ordered list of a user's favorites stored at the PDA
+/// `[FAVORITES_LIST_SEED, owner]`.
+///
+/// The account is allocated at full capacity up front so adding an item never
+/// needs a realloc; `len` tracks how many slots of `items` are in use.
+#[derive(Clone, Copy, Pod, Zeroable, ShankAccount)]
+#[repr(C)]
+pub struct FavoritesList {
+    pub owner: Pubkey,
+    /// Item names, UTF-8 padded with zeroes
+    #[idl_type("[[u8; 32]; 16]")]
+    pub items: [[u8; 32]; MAX_FAVORITES],
+    pub len: u8,
+    pub bump: u8,
+}
+
+impl FavoritesList {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    pub fn set_inner(&mut self, data: Self) -> Self {
+        *self = data;
+        *self
+    }
+
+    pub fn items(&self) -> &[[u8; 32]] {
+        &self.items[..self.len as usize]
+    }
+
+    pub fn push(&mut self, item: [u8; 32]) -> Result<(), FavoritesError> {
+        // An all-zero item is indistinguishable from an unused slot
+        if item == [0; 32] {
+            return Err(FavoritesError::EmptyFavorite);
+        }
+        if self.items().contains(&item) {
+            return Err(FavoritesError::DuplicateFavorite);
+        }
+        let len = self.len as usize;
+        if len == MAX_FAVORITES {
+            return Err(FavoritesError::ListFull);
+        }
+
+        self.items[len] = item;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Remove the item at `index`, shifting later items down so the order
+    /// seen by paginated reads stays stable.
+    pub fn remove(&mut self, index: u8) -> Result<[u8; 32], FavoritesError> {
+        let index = index as usize;
+        let len = self.len as usize;
+        if index >= len {
+            return Err(FavoritesError::IndexOutOfBounds);
+        }
+
+        let removed = self.items[index];
+        self.items.copy_within(index + 1..len, index);
+        self.items[len - 1] = [0; 32];
+        self.len -= 1;
+        Ok(removed)
+    }
+
+    /// Up to `limit` items starting at `offset`; empty past the end.
+    pub fn page(&self, offset: u8, limit: u8) -> &[[u8; 32]] {
+        let items = self.items();
+        let start = (offset as usize).min(items.len());
+        let end = start.saturating_add(limit as usize).min(items.len());
+        &items[start..end]
+    }
+}
@@ -1,3 +1,5 @@
 pub mod account;
+pub mod favorites_list;
 
 pub use account::*;
+pub use favorites_list::*;
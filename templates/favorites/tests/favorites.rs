@@ -3,14 +3,425 @@
 
 #[cfg(test)]
 mod tests {
-    use favorites::ID;
-    use solana_sdk::pubkey::Pubkey;
+    use favorites::{
+        constants::{FAVORITES_LIST_SEED, FAVORITES_SEED, MAX_FAVORITES},
+        error::FavoritesError,
+        processor::{
+            AddFavoriteIxsData, CreatePdaIxsData, GetFavoritesIxsData, RemoveFavoriteIxsData,
+            UpdateFavoritesIxsData,
+        },
+        state::{Favorites, FavoritesList},
+        ID,
+    };
+    use mollusk_svm::{result::Check, Mollusk};
+    use pinocchio_helper::le::U64LE;
+    use solana_sdk::{
+        account::{Account, AccountSharedData},
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
 
     pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
 
+    fn item(name: &str) -> [u8; 32] {
+        let mut item = [0u8; 32];
+        item[..name.len()].copy_from_slice(name.as_bytes());
+        item
+    }
+
+    fn text(value: &str) -> [u8; 50] {
+        let mut text = [0u8; 50];
+        text[..value.len()].copy_from_slice(value.as_bytes());
+        text
+    }
+
+    fn add_favorite(
+        mollusk: &Mollusk,
+        user: Pubkey,
+        list: Pubkey,
+        name: &str,
+        accounts: &[(Pubkey, Account)],
+        checks: &[Check],
+    ) -> Vec<(Pubkey, Account)> {
+        let (system_program, _) = mollusk_svm::program::keyed_account_for_system_program();
+        let ix_data = AddFavoriteIxsData { item: item(name) };
+        let data = [vec![3], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(user, true),
+                AccountMeta::new(list, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        let result = mollusk.process_and_validate_instruction(&instruction, accounts, checks);
+        accounts
+            .iter()
+            .map(|(key, _)| (*key, result.get_account(key).unwrap().clone()))
+            .collect()
+    }
+
+    /// A fresh user, their favorites list PDA, and the accounts `add_favorite`
+    /// runs against
+    fn new_list() -> (Pubkey, Pubkey, Vec<(Pubkey, Account)>) {
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let user = Pubkey::new_unique();
+        let (list, _) =
+            Pubkey::find_program_address(&[FAVORITES_LIST_SEED, user.as_ref()], &PROGRAM_ID);
+        let accounts = vec![
+            (
+                user,
+                AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+            ),
+            (list, AccountSharedData::new(0, 0, &system_program).into()),
+            (system_program, system_account),
+        ];
+        (user, list, accounts)
+    }
+
+    fn remove_favorite_instruction(user: Pubkey, list: Pubkey, index: u8) -> Instruction {
+        let ix_data = RemoveFavoriteIxsData { index };
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![4], bytemuck::bytes_of(&ix_data).to_vec()].concat(),
+            vec![
+                AccountMeta::new_readonly(user, true),
+                AccountMeta::new(list, false),
+            ],
+        )
+    }
+
     #[test]
     fn test_program_id() {
         // Basic test that verifies program ID is set correctly
         assert_ne!(PROGRAM_ID, Pubkey::default());
     }
+
+    #[test]
+    fn test_create_pda_requires_writable_favorites() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let user = Pubkey::new_unique();
+        let (favorites_pubkey, bump) =
+            Pubkey::find_program_address(&[FAVORITES_SEED, user.as_ref()], &PROGRAM_ID);
+
+        let ix_data = CreatePdaIxsData {
+            number: U64LE::new(7),
+            color: text("red"),
+            hobby1: text("chess"),
+            hobby2: [0; 50],
+            hobby3: [0; 50],
+            hobby4: [0; 50],
+            hobby5: [0; 50],
+            bump,
+        };
+        let data = [vec![0], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(user, true),
+                AccountMeta::new_readonly(favorites_pubkey, false),
+                AccountMeta::new_readonly(system_program, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    user,
+                    AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+                ),
+                (
+                    favorites_pubkey,
+                    AccountSharedData::new(0, 0, &system_program).into(),
+                ),
+                (system_program, system_account),
+            ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    #[test]
+    fn test_update_favorites_keeps_bump() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+
+        let user = Pubkey::new_unique();
+        let (favorites_pubkey, bump) =
+            Pubkey::find_program_address(&[FAVORITES_SEED, user.as_ref()], &PROGRAM_ID);
+
+        let existing = Favorites {
+            number: U64LE::new(7),
+            color: text("red"),
+            hobby1: text("chess"),
+            hobby2: [0; 50],
+            hobby3: [0; 50],
+            hobby4: [0; 50],
+            hobby5: [0; 50],
+            bump,
+        };
+        let mut favorites_account =
+            AccountSharedData::new(LAMPORTS_PER_SOL, Favorites::LEN, &PROGRAM_ID);
+        favorites_account.set_data_from_slice(bytemuck::bytes_of(&existing));
+
+        let ix_data = UpdateFavoritesIxsData {
+            number: U64LE::new(42),
+            color: text("blue"),
+            hobby1: text("climbing"),
+            hobby2: text("piano"),
+            hobby3: [0; 50],
+            hobby4: [0; 50],
+            hobby5: [0; 50],
+        };
+        let data = [vec![2], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new_readonly(user, true),
+                AccountMeta::new(favorites_pubkey, false),
+            ],
+        );
+
+        let expected = Favorites {
+            number: ix_data.number,
+            color: ix_data.color,
+            hobby1: ix_data.hobby1,
+            hobby2: ix_data.hobby2,
+            hobby3: ix_data.hobby3,
+            hobby4: ix_data.hobby4,
+            hobby5: ix_data.hobby5,
+            bump,
+        };
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    user,
+                    AccountSharedData::new(LAMPORTS_PER_SOL, 0, &Pubkey::default()).into(),
+                ),
+                (favorites_pubkey, favorites_account.into()),
+            ],
+            &[
+                Check::success(),
+                Check::account(&favorites_pubkey)
+                    .data(bytemuck::bytes_of(&expected))
+                    .build(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_favorites() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let user = Pubkey::new_unique();
+        let (list, _) =
+            Pubkey::find_program_address(&[FAVORITES_LIST_SEED, user.as_ref()], &PROGRAM_ID);
+
+        let mut accounts = vec![
+            (
+                user,
+                AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+            ),
+            (list, AccountSharedData::new(0, 0, &system_program).into()),
+            (system_program, system_account),
+        ];
+
+        for name in ["tea", "jazz", "rain"] {
+            accounts = add_favorite(&mollusk, user, list, name, &accounts, &[Check::success()]);
+        }
+
+        let stored = bytemuck::from_bytes::<FavoritesList>(&accounts[1].1.data);
+        assert_eq!(accounts[1].1.owner, PROGRAM_ID);
+        assert_eq!(stored.owner, user.to_bytes());
+        assert_eq!(stored.items(), &[item("tea"), item("jazz"), item("rain")]);
+
+        let ix_data = RemoveFavoriteIxsData { index: 1 };
+        let data = [vec![4], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new_readonly(user, true),
+                AccountMeta::new(list, false),
+            ],
+        );
+        let result = mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts[..2],
+            &[Check::success()],
+        );
+
+        let stored =
+            bytemuck::from_bytes::<FavoritesList>(&result.get_account(&list).unwrap().data);
+        assert_eq!(stored.items(), &[item("tea"), item("rain")]);
+    }
+
+    #[test]
+    fn test_add_favorite_rejects_duplicate_and_full_list() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+
+        let user = Pubkey::new_unique();
+        let (list, _) =
+            Pubkey::find_program_address(&[FAVORITES_LIST_SEED, user.as_ref()], &PROGRAM_ID);
+
+        let mut accounts = vec![
+            (
+                user,
+                AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program).into(),
+            ),
+            (list, AccountSharedData::new(0, 0, &system_program).into()),
+            (system_program, system_account),
+        ];
+
+        accounts = add_favorite(
+            &mollusk,
+            user,
+            list,
+            "item-0",
+            &accounts,
+            &[Check::success()],
+        );
+        add_favorite(
+            &mollusk,
+            user,
+            list,
+            "item-0",
+            &accounts,
+            &[Check::err(ProgramError::Custom(
+                FavoritesError::DuplicateFavorite as u32,
+            ))],
+        );
+
+        for i in 1..MAX_FAVORITES {
+            let name = format!("item-{i}");
+            accounts = add_favorite(&mollusk, user, list, &name, &accounts, &[Check::success()]);
+        }
+        add_favorite(
+            &mollusk,
+            user,
+            list,
+            "one-too-many",
+            &accounts,
+            &[Check::err(ProgramError::Custom(
+                FavoritesError::ListFull as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_add_favorite_rejects_empty_item() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (user, list, accounts) = new_list();
+
+        add_favorite(
+            &mollusk,
+            user,
+            list,
+            "",
+            &accounts,
+            &[Check::err(ProgramError::Custom(
+                FavoritesError::EmptyFavorite as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_add_favorite_rejects_non_canonical_list() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (user, _, mut accounts) = new_list();
+
+        // Any address other than the canonical PDA is refused
+        let list = Pubkey::new_unique();
+        accounts[1].0 = list;
+
+        add_favorite(
+            &mollusk,
+            user,
+            list,
+            "tea",
+            &accounts,
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+
+    #[test]
+    fn test_remove_favorite_rejects_index_out_of_bounds() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (user, list, accounts) = new_list();
+        let accounts = add_favorite(&mollusk, user, list, "tea", &accounts, &[Check::success()]);
+
+        mollusk.process_and_validate_instruction(
+            &remove_favorite_instruction(user, list, 1),
+            &accounts[..2],
+            &[Check::err(ProgramError::Custom(
+                FavoritesError::IndexOutOfBounds as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_remove_favorite_rejects_other_users_list() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (user, list, accounts) = new_list();
+        let accounts = add_favorite(&mollusk, user, list, "tea", &accounts, &[Check::success()]);
+
+        let intruder = Pubkey::new_unique();
+        mollusk.process_and_validate_instruction(
+            &remove_favorite_instruction(intruder, list, 0),
+            &[
+                (
+                    intruder,
+                    AccountSharedData::new(LAMPORTS_PER_SOL, 0, &accounts[2].0).into(),
+                ),
+                accounts[1].clone(),
+            ],
+            &[Check::err(ProgramError::Custom(
+                FavoritesError::InvalidListOwner as u32,
+            ))],
+        );
+    }
+
+    #[test]
+    fn test_get_favorites_reads_readonly_list() {
+        let mollusk = test_support::mollusk(&PROGRAM_ID, "favorites");
+        let (user, list, accounts) = new_list();
+        let accounts = add_favorite(&mollusk, user, list, "tea", &accounts, &[Check::success()]);
+
+        let ix_data = GetFavoritesIxsData {
+            offset: 0,
+            limit: 8,
+        };
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![5], bytemuck::bytes_of(&ix_data).to_vec()].concat(),
+            vec![
+                AccountMeta::new_readonly(user, false),
+                AccountMeta::new_readonly(list, false),
+            ],
+        );
+
+        mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts[..2],
+            &[
+                Check::success(),
+                Check::account(&list).data(&accounts[1].1.data).build(),
+            ],
+        );
+    }
 }
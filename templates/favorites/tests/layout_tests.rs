@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use favorites::{
+        processor::{
+            AddFavoriteIxsData, CreatePdaIxsData, GetFavoritesIxsData, RemoveFavoriteIxsData,
+            UpdateFavoritesIxsData,
+        },
+        state::{Favorites, FavoritesList},
+    };
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_favorites_layout() {
+        assert_layout!(Favorites, size = 309, {
+            number => 0,
+            color => 8,
+            hobby1 => 58,
+            hobby2 => 108,
+            hobby3 => 158,
+            hobby4 => 208,
+            hobby5 => 258,
+            bump => 308,
+        });
+    }
+
+    #[test]
+    fn test_create_pda_ixs_data_layout() {
+        assert_layout!(CreatePdaIxsData, size = 309, {
+            number => 0,
+            color => 8,
+            hobby1 => 58,
+            hobby2 => 108,
+            hobby3 => 158,
+            hobby4 => 208,
+            hobby5 => 258,
+            bump => 308,
+        });
+    }
+
+    #[test]
+    fn test_update_favorites_ixs_data_layout() {
+        assert_layout!(UpdateFavoritesIxsData, size = 308, {
+            number => 0,
+            color => 8,
+            hobby1 => 58,
+            hobby2 => 108,
+            hobby3 => 158,
+            hobby4 => 208,
+            hobby5 => 258,
+        });
+    }
+
+    #[test]
+    fn test_favorites_list_layout() {
+        assert_layout!(FavoritesList, size = 546, {
+            owner => 0,
+            items => 32,
+            len => 544,
+            bump => 545,
+        });
+    }
+
+    #[test]
+    fn test_favorites_list_ixs_data_layout() {
+        assert_layout!(AddFavoriteIxsData, size = 32, { item => 0 });
+        assert_layout!(RemoveFavoriteIxsData, size = 1, { index => 0 });
+        assert_layout!(GetFavoritesIxsData, size = 2, { offset => 0, limit => 1 });
+    }
+}
@@ -0,0 +1,125 @@
+//! Compute-unit comparison tables.
+//!
+//! A benchmark runs the same flow against two or more programs (e.g. this
+//! repo's Pinocchio counter and an equivalent Anchor build), records the
+//! `compute_units_consumed` of each step and renders them side by side as a
+//! markdown table, with each column's ratio to the first one.
+//!
+//! Programs that are not built from this workspace are loaded from
+//! `tests/fixtures/<name>.so` in the crate under test; see [`fixture_path`].
+//! Reports go to `<target dir>/benches/<crate>/` so a plain `cargo test`
+//! leaves the source tree alone. Set `$MOLLUSK_BENCH_DIR` to write them
+//! somewhere else, e.g. `MOLLUSK_BENCH_DIR=benches` to refresh the table
+//! committed next to the crate; relative paths are resolved against the crate
+//! under test.
+
+use std::{env, fmt::Write as _, fs, io, path::PathBuf};
+
+/// Opts into writing benchmark reports to this directory instead of the
+/// target dir.
+pub const BENCH_DIR: &str = "MOLLUSK_BENCH_DIR";
+
+/// Path of `tests/fixtures/<name>.so` without the extension, as
+/// `Mollusk::new` expects, or `None` when the fixture is not checked out.
+pub fn fixture_path(name: &str) -> Option<String> {
+    let artifact = crate::manifest_dir()
+        .join("tests")
+        .join("fixtures")
+        .join(name);
+    artifact
+        .with_extension("so")
+        .exists()
+        .then(|| artifact.to_string_lossy().into_owned())
+}
+
+/// Compute units per step, one column per program.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    title: String,
+    columns: Vec<String>,
+    rows: Vec<(String, Vec<Option<u64>>)>,
+    notes: Vec<String>,
+}
+
+impl Comparison {
+    pub fn new(title: &str, columns: &[&str]) -> Self {
+        Self {
+            title: title.to_string(),
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    /// Add a step. `None` marks a program that was not measured.
+    pub fn row(&mut self, step: &str, compute_units: &[Option<u64>]) -> &mut Self {
+        assert_eq!(
+            compute_units.len(),
+            self.columns.len(),
+            "one value per column"
+        );
+        self.rows.push((step.to_string(), compute_units.to_vec()));
+        self
+    }
+
+    /// Add a line printed under the table, e.g. why a column is empty.
+    pub fn note(&mut self, note: &str) -> &mut Self {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n\n| Step |", self.title);
+        for column in &self.columns {
+            let _ = write!(out, " {column} (CU) |");
+        }
+        for column in self.columns.iter().skip(1) {
+            let _ = write!(out, " {column} / {} |", self.columns[0]);
+        }
+        out.push_str("\n|---|");
+        out.push_str(&"---:|".repeat(self.columns.len() * 2 - 1));
+        out.push('\n');
+
+        for (step, values) in &self.rows {
+            let _ = write!(out, "| {step} |");
+            for value in values {
+                match value {
+                    Some(cu) => {
+                        let _ = write!(out, " {cu} |");
+                    }
+                    None => out.push_str(" n/a |"),
+                }
+            }
+            for value in values.iter().skip(1) {
+                match (values[0], *value) {
+                    (Some(base), Some(cu)) if base > 0 => {
+                        let _ = write!(out, " {:.2}x |", cu as f64 / base as f64);
+                    }
+                    _ => out.push_str(" n/a |"),
+                }
+            }
+            out.push('\n');
+        }
+
+        for note in &self.notes {
+            let _ = write!(out, "\n{note}\n");
+        }
+        out
+    }
+
+    /// Write the table to `<bench dir>/<name>.md` and return the path.
+    pub fn write(&self, name: &str) -> io::Result<PathBuf> {
+        let manifest_dir = crate::manifest_dir();
+        let dir = match env::var_os(BENCH_DIR) {
+            Some(dir) => manifest_dir.join(dir),
+            None => {
+                let krate = manifest_dir.file_name().unwrap_or_default();
+                crate::target_dir().join("benches").join(krate)
+            }
+        };
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(name).with_extension("md");
+        fs::write(&path, self.to_markdown())?;
+        Ok(path)
+    }
+}
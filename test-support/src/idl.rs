@@ -0,0 +1,86 @@
+//! IDL drift checks.
+//!
+//! Shank builds the IDL from the `#[account(..)]` annotations on each
+//! program's instruction enum, while handlers validate the account list
+//! declared with `pinocchio_helper::instruction_accounts!`. Nothing ties the
+//! two together at compile time, so each program has a test comparing every
+//! `ACCOUNTS` table with the committed IDL, and CI regenerates the IDL with
+//! `shank idl` and fails if it differs from the committed file.
+//!
+//! On a mismatch the test prints the `#[account(..)]` annotations that match
+//! the handler, generated from its `ACCOUNTS`, ready to paste over the enum's.
+
+use pinocchio_helper::accounts::AccountMeta;
+use serde_json::Value;
+
+/// Shank emits account names in camelCase
+fn camel_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Assert that `instruction` in `idl` (the IDL JSON) lists exactly `accounts`,
+/// in order, with the same signer and writable flags.
+pub fn assert_accounts_match(idl: &str, instruction: &str, accounts: &[AccountMeta]) {
+    let idl: Value = serde_json::from_str(idl).expect("IDL is not valid JSON");
+    let idl_accounts = idl["instructions"]
+        .as_array()
+        .expect("IDL has no instructions")
+        .iter()
+        .find(|ix| ix["name"] == instruction)
+        .unwrap_or_else(|| panic!("{instruction} missing from IDL"))["accounts"]
+        .as_array()
+        .unwrap_or_else(|| panic!("{instruction} has no accounts in the IDL"));
+
+    let expected: Vec<(String, bool, bool)> = accounts
+        .iter()
+        .map(|a| (camel_case(a.name), a.is_signer, a.is_writable))
+        .collect();
+    let actual: Vec<(String, bool, bool)> = idl_accounts
+        .iter()
+        .map(|a| {
+            (
+                a["name"].as_str().unwrap().to_string(),
+                a["isSigner"].as_bool().unwrap(),
+                a["isMut"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        actual,
+        expected,
+        "{instruction} accounts drifted from the IDL; annotations matching the handler:\n{}",
+        shank_annotations(accounts)
+    );
+}
+
+/// The `#[account(..)]` attributes Shank needs to describe `accounts`.
+/// Descriptions are not part of `ACCOUNTS`, so none are emitted.
+pub fn shank_annotations(accounts: &[AccountMeta]) -> String {
+    accounts
+        .iter()
+        .enumerate()
+        .map(|(index, account)| {
+            let mut flags = String::new();
+            if account.is_writable {
+                flags.push_str("writable, ");
+            }
+            if account.is_signer {
+                flags.push_str("signer, ");
+            }
+            format!("#[account({index}, {flags}name = \"{}\")]\n", account.name)
+        })
+        .collect()
+}
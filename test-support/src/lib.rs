@@ -0,0 +1,139 @@
+//! Shared helpers for the Mollusk test suites.
+//!
+//! Program artifacts are resolved at runtime instead of through hardcoded
+//! relative paths, so tests can be run from any directory:
+//!
+//! 1. `$SBF_OUT_DIR/<program>` when the variable is set
+//! 2. otherwise `<target dir>/deploy/<program>`, where the target dir is
+//!    `$CARGO_TARGET_DIR` or `target/` under the nearest enclosing workspace
+//!
+//! Set `MOLLUSK_BUILD_SBF=1` to run `cargo build-sbf` for the crate under test
+//! when the artifact is missing.
+//!
+//! [`snapshot`] saves and restores account sets so expensive setup can be
+//! shared between tests or attached to bug reports.
+//!
+//! [`bench`] renders compute-unit comparisons between programs as markdown
+//! under the target dir, or into the source tree when `MOLLUSK_BENCH_DIR` is
+//! set.
+//!
+//! [`idl`] checks instruction account tables against the committed IDL.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Once,
+};
+
+use mollusk_svm::Mollusk;
+use solana_sdk::pubkey::Pubkey;
+
+pub mod bench;
+pub mod idl;
+pub mod snapshot;
+
+/// Overrides the directory program artifacts are loaded from.
+pub const SBF_OUT_DIR: &str = "SBF_OUT_DIR";
+
+/// Set to `1` to build missing artifacts with `cargo build-sbf`.
+pub const BUILD_SBF: &str = "MOLLUSK_BUILD_SBF";
+
+static BUILD: Once = Once::new();
+
+/// Create a `Mollusk` instance for `program_name` (the artifact name, e.g. `"counter"`).
+pub fn mollusk(program_id: &Pubkey, program_name: &str) -> Mollusk {
+    Mollusk::new(program_id, &program_path(program_name))
+}
+
+/// Path of `<program_name>.so`, without the extension as `Mollusk::new` expects.
+pub fn program_path(program_name: &str) -> String {
+    let deploy_dir = deploy_dir();
+    let artifact = deploy_dir.join(program_name);
+
+    if !artifact.with_extension("so").exists() && env::var(BUILD_SBF).as_deref() == Ok("1") {
+        BUILD.call_once(|| build_sbf(&deploy_dir));
+    }
+
+    artifact.to_string_lossy().into_owned()
+}
+
+/// Nearest ancestor of `start` (inclusive) whose `Cargo.toml` declares a `[workspace]`.
+///
+/// Falls back to `start` for crates outside any workspace.
+pub fn workspace_root(start: &Path) -> PathBuf {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .map(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+                .unwrap_or(false)
+        })
+        .unwrap_or(start)
+        .to_path_buf()
+}
+
+fn deploy_dir() -> PathBuf {
+    if let Some(dir) = env::var_os(SBF_OUT_DIR) {
+        return PathBuf::from(dir);
+    }
+    target_dir().join("deploy")
+}
+
+/// `$CARGO_TARGET_DIR`, or `target/` under the nearest enclosing workspace.
+pub(crate) fn target_dir() -> PathBuf {
+    match env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_root(&manifest_dir()).join("target"),
+    }
+}
+
+/// Manifest directory of the crate whose tests are running.
+pub(crate) fn manifest_dir() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| env::current_dir().expect("current directory is not accessible"))
+}
+
+fn build_sbf(deploy_dir: &Path) {
+    let status = Command::new("cargo")
+        .arg("build-sbf")
+        .arg("--manifest-path")
+        .arg(manifest_dir().join("Cargo.toml"))
+        .arg("--sbf-out-dir")
+        .arg(deploy_dir)
+        .status()
+        .expect("failed to run cargo build-sbf");
+
+    assert!(status.success(), "cargo build-sbf failed");
+}
+
+/// Asserts the byte offset of every listed field and the total size of a
+/// `#[repr(C)]` struct.
+///
+/// Deployed accounts are read back with `bytemuck`, so a reordered field or new
+/// padding silently corrupts existing data. Listing the layout in a test turns
+/// that into a failure the moment the struct changes:
+///
+/// ```ignore
+/// assert_layout!(Counter, size = 8, {
+///     count => 0,
+/// });
+/// ```
+#[macro_export]
+macro_rules! assert_layout {
+    ($ty:ty, size = $size:expr, { $($field:ident => $offset:expr),* $(,)? }) => {{
+        $(
+            assert_eq!(
+                core::mem::offset_of!($ty, $field),
+                $offset,
+                concat!("offset of ", stringify!($ty), "::", stringify!($field), " changed"),
+            );
+        )*
+        assert_eq!(
+            core::mem::size_of::<$ty>(),
+            $size,
+            concat!("size of ", stringify!($ty), " changed"),
+        );
+    }};
+}
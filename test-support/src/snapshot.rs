@@ -0,0 +1,158 @@
+//! Account-set snapshots.
+//!
+//! A test that builds up expensive state (a funded pool, a counter after a
+//! sequence of mutations) can save the resulting accounts once and let other
+//! tests start from the file instead of replaying the setup. Snapshots also
+//! make a failing case reproducible: save the accounts at the point of failure
+//! and attach the file to the bug report.
+//!
+//! Snapshots are written to `$MOLLUSK_SNAPSHOT_DIR` when set, otherwise to
+//! `tests/snapshots/` in the crate under test; [`temp_path`] gives throwaway
+//! snapshots a unique file instead. The format is a small little-endian
+//! binary layout, so it has no serialization dependency:
+//!
+//! ```text
+//! magic "MSNAP\0" | version u16 | count u32
+//! per account: pubkey [32] | lamports u64 | owner [32] | executable u8
+//!              | rent_epoch u64 | data_len u32 | data
+//! ```
+
+use std::{
+    env, fs,
+    io::{self, Error, ErrorKind},
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+/// Overrides the directory snapshots are read from and written to.
+pub const SNAPSHOT_DIR: &str = "MOLLUSK_SNAPSHOT_DIR";
+
+const MAGIC: &[u8; 6] = b"MSNAP\0";
+const VERSION: u16 = 1;
+
+/// Path of the snapshot called `name`.
+pub fn snapshot_path(name: &str) -> PathBuf {
+    let dir = match env::var_os(SNAPSHOT_DIR) {
+        Some(dir) => PathBuf::from(dir),
+        None => crate::manifest_dir().join("tests").join("snapshots"),
+    };
+    dir.join(name).with_extension("snap")
+}
+
+/// A fresh path under the system temp dir for a snapshot that should not
+/// outlive the test. Unique per process and per call, so tests running in
+/// parallel (or concurrent `cargo test` runs) never share a file.
+pub fn temp_path(name: &str) -> PathBuf {
+    static NEXT: AtomicUsize = AtomicUsize::new(0);
+    let id = NEXT.fetch_add(1, Ordering::Relaxed);
+    env::temp_dir()
+        .join(format!("{name}-{}-{id}", process::id()))
+        .with_extension("snap")
+}
+
+/// Write `accounts` to the snapshot called `name`, creating the directory if needed.
+pub fn save(name: &str, accounts: &[(Pubkey, Account)]) -> io::Result<PathBuf> {
+    let path = snapshot_path(name);
+    save_to(&path, accounts)?;
+    Ok(path)
+}
+
+/// Read the snapshot called `name`, in the order it was saved.
+pub fn load(name: &str) -> io::Result<Vec<(Pubkey, Account)>> {
+    load_from(&snapshot_path(name))
+}
+
+pub fn save_to(path: &Path, accounts: &[(Pubkey, Account)]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, encode(accounts))
+}
+
+pub fn load_from(path: &Path) -> io::Result<Vec<(Pubkey, Account)>> {
+    decode(&fs::read(path)?)
+}
+
+pub fn encode(accounts: &[(Pubkey, Account)]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&VERSION.to_le_bytes());
+    out.extend_from_slice(&(accounts.len() as u32).to_le_bytes());
+
+    for (pubkey, account) in accounts {
+        out.extend_from_slice(pubkey.as_ref());
+        out.extend_from_slice(&account.lamports.to_le_bytes());
+        out.extend_from_slice(account.owner.as_ref());
+        out.push(account.executable as u8);
+        out.extend_from_slice(&account.rent_epoch.to_le_bytes());
+        out.extend_from_slice(&(account.data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&account.data);
+    }
+
+    out
+}
+
+pub fn decode(bytes: &[u8]) -> io::Result<Vec<(Pubkey, Account)>> {
+    let mut reader = Reader(bytes);
+
+    if reader.take(MAGIC.len())? != MAGIC {
+        return Err(invalid("not a snapshot file"));
+    }
+    let version = u16::from_le_bytes(reader.array()?);
+    if version != VERSION {
+        return Err(invalid("unsupported snapshot version"));
+    }
+
+    let count = u32::from_le_bytes(reader.array()?);
+    let mut accounts = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let pubkey = Pubkey::new_from_array(reader.array()?);
+        let lamports = u64::from_le_bytes(reader.array()?);
+        let owner = Pubkey::new_from_array(reader.array()?);
+        let executable = reader.take(1)?[0] != 0;
+        let rent_epoch = u64::from_le_bytes(reader.array()?);
+        let data_len = u32::from_le_bytes(reader.array()?) as usize;
+        let data = reader.take(data_len)?.to_vec();
+
+        accounts.push((
+            pubkey,
+            Account {
+                lamports,
+                data,
+                owner,
+                executable,
+                rent_epoch,
+            },
+        ));
+    }
+
+    if !reader.0.is_empty() {
+        return Err(invalid("trailing bytes after last account"));
+    }
+
+    Ok(accounts)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "truncated snapshot"));
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pinocchio_helper::accounts::AccountMeta;
+    use solana_sdk::{account::Account, pubkey::Pubkey};
+    use test_support::{
+        assert_layout,
+        bench::{Comparison, BENCH_DIR},
+        idl, snapshot, workspace_root,
+    };
+
+    #[test]
+    fn test_workspace_root_from_member() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        assert_eq!(workspace_root(manifest_dir), manifest_dir.parent().unwrap());
+    }
+
+    #[test]
+    fn test_workspace_root_outside_workspace() {
+        let dir = std::env::temp_dir();
+
+        assert_eq!(workspace_root(&dir), dir);
+    }
+
+    #[test]
+    fn test_assert_layout_accepts_matching_table() {
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Sample {
+            tag: u8,
+            value: [u8; 8],
+        }
+
+        assert_layout!(Sample, size = 9, {
+            tag => 0,
+            value => 1,
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "offset of Sample::value changed")]
+    fn test_assert_layout_rejects_moved_field() {
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Sample {
+            tag: u8,
+            value: [u8; 8],
+        }
+
+        assert_layout!(Sample, size = 9, {
+            value => 0,
+        });
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let accounts = vec![
+            (
+                Pubkey::new_unique(),
+                Account {
+                    lamports: 1_000_000,
+                    data: vec![1, 2, 3, 4],
+                    owner: Pubkey::new_unique(),
+                    executable: false,
+                    rent_epoch: u64::MAX,
+                },
+            ),
+            (Pubkey::new_unique(), Account::default()),
+        ];
+
+        let path = snapshot::temp_path("test_support_roundtrip");
+        snapshot::save_to(&path, &accounts).unwrap();
+        let loaded = snapshot::load_from(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, accounts);
+    }
+
+    #[test]
+    fn test_snapshot_rejects_truncated_file() {
+        let bytes = snapshot::encode(&[(Pubkey::new_unique(), Account::default())]);
+
+        assert!(snapshot::decode(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_comparison_markdown_ratios() {
+        let mut comparison = Comparison::new("Counter", &["Pinocchio", "Anchor"]);
+        comparison
+            .row("Increase", &[Some(100), Some(250)])
+            .row("Decrease", &[Some(100), None])
+            .note("Anchor fixture missing for Decrease");
+
+        assert_eq!(
+            comparison.to_markdown(),
+            "# Counter\n\n\
+             | Step | Pinocchio (CU) | Anchor (CU) | Anchor / Pinocchio |\n\
+             |---|---:|---:|---:|\n\
+             | Increase | 100 | 250 | 2.50x |\n\
+             | Decrease | 100 | n/a | n/a |\n\
+             \nAnchor fixture missing for Decrease\n"
+        );
+    }
+
+    #[test]
+    fn test_comparison_write_stays_out_of_source_tree() {
+        if std::env::var_os(BENCH_DIR).is_some() {
+            return;
+        }
+        let path = Comparison::new("Write", &["Pinocchio"])
+            .row("Step", &[Some(1)])
+            .write("write_default")
+            .unwrap();
+
+        assert!(!path.starts_with(Path::new(env!("CARGO_MANIFEST_DIR"))));
+        assert!(path.ends_with("benches/test-support/write_default.md"));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_shank_annotations_from_accounts() {
+        let accounts = [
+            AccountMeta {
+                name: "maker",
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                name: "system_program",
+                is_signer: false,
+                is_writable: false,
+            },
+        ];
+
+        assert_eq!(
+            idl::shank_annotations(&accounts),
+            "#[account(0, writable, signer, name = \"maker\")]\n\
+             #[account(1, name = \"system_program\")]\n"
+        );
+    }
+}
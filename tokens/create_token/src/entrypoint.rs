@@ -17,12 +17,8 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let (discriminator, data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-
-    match Instruction::try_from(discriminator)? {
-        Instruction::CreateToken => {
+    match Instruction::try_from(instruction_data)? {
+        Instruction::CreateToken(data) => {
             log!("Instruction: CreateToken");
             CreateToken::try_from((accounts, data))?.handler()
         }
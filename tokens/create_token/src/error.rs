@@ -1,13 +1,9 @@
-use pinocchio::program_error::ProgramError;
+use pinocchio_helper::program_error;
 
-/// Errors that may be returned by myproject.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum MyProjectError {
-    Invalid,
-}
-
-impl From<MyProjectError> for ProgramError {
-    fn from(e: MyProjectError) -> Self {
-        ProgramError::Custom(e as u32)
+program_error! {
+    /// Errors returned by the create-token program
+    pub enum CreateTokenError {
+        /// Placeholder for program-specific failures
+        Invalid = 6001,
     }
 }
@@ -3,52 +3,24 @@ use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
     pubkey::*,
-    sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 
+use pinocchio_helper::{cpi::create_account, instruction_accounts};
 use pinocchio_token::state::Mint;
 
 use crate::constants::CREATE_TOKEN_SEED;
 
-pub struct CreateTokenIxsAccounts<'info> {
-    pub payer: &'info AccountInfo,
-    pub mint: &'info AccountInfo,
-    pub token_program: &'info AccountInfo,
-}
-
-impl<'info> TryFrom<&'info [AccountInfo]> for CreateTokenIxsAccounts<'info> {
-    type Error = ProgramError;
-
-    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
-        let [payer, mint, token_program, _] = accounts else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-
-        if !payer.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if !mint.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if mint.data_len() != 0 {
-            return Err(ProgramError::AccountAlreadyInitialized);
-        }
-
-        if !token_program.executable() {
-            return Err(ProgramError::IncorrectProgramId);
-        }
-
-        Ok(Self {
-            payer,
-            mint,
-            token_program,
-        })
+instruction_accounts! {
+    pub struct CreateTokenIxsAccounts {
+        [signer, writable] payer,
+        [signer, writable, empty] mint,
+        [executable] token_program,
+        system_program,
     }
 }
 
+#[derive(shank::ShankType)]
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct CreateTokenIxsData {
@@ -76,28 +48,25 @@ pub struct CreateToken<'info> {
     pub data: CreateTokenIxsData,
 }
 
-impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreateToken<'info> {
+impl<'info> TryFrom<(&'info [AccountInfo], CreateTokenIxsData)> for CreateToken<'info> {
     type Error = ProgramError;
 
     fn try_from(
-        (accounts, data): (&'info [AccountInfo], &'info [u8]),
+        (accounts, data): (&'info [AccountInfo], CreateTokenIxsData),
     ) -> Result<Self, Self::Error> {
         let accounts = CreateTokenIxsAccounts::try_from(accounts)?;
-        let data = CreateTokenIxsData::try_from(data)?;
         Ok(Self { accounts, data })
     }
 }
 
 impl<'info> CreateToken<'info> {
     pub fn handler(&mut self) -> ProgramResult {
-        pinocchio_system::instructions::CreateAccount {
-            from: self.accounts.payer,
-            to: self.accounts.mint,
-            space: Mint::LEN as u64,
-            lamports: Rent::get()?.minimum_balance(Mint::LEN),
-            owner: &self.accounts.token_program.key(),
-        }
-        .invoke()?;
+        create_account(
+            self.accounts.payer,
+            self.accounts.mint,
+            Mint::LEN,
+            self.accounts.token_program.key(),
+        )?;
 
         pinocchio_token::instructions::InitializeMint2 {
             mint: self.accounts.mint,
@@ -17,15 +17,21 @@ pub enum Instruction {
     )]
     #[account(2, name = "token_program", desc = "The token program to use")]
     #[account(3, name = "system_program", desc = "The system program")]
-    CreateToken,
+    CreateToken(CreateTokenIxsData),
 }
 
-impl TryFrom<&u8> for Instruction {
+/// Splits off the discriminator and parses the payload into the variant, so
+/// the arguments Shank reads from each variant are the ones handlers receive.
+impl TryFrom<&[u8]> for Instruction {
     type Error = ProgramError;
 
-    fn try_from(value: &u8) -> Result<Self, Self::Error> {
-        match *value {
-            0 => Ok(Instruction::CreateToken),
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let (discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            0 => Ok(Instruction::CreateToken(CreateTokenIxsData::try_from(data)?)),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
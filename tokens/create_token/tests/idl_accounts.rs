@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod tests {
+    use create_token::processor::CreateTokenIxsAccounts;
+    use test_support::idl::assert_accounts_match;
+
+    const IDL: &str = include_str!("../../../idl/create_token.json");
+
+    #[test]
+    fn test_create_token_accounts_match_idl() {
+        assert_accounts_match(IDL, "CreateToken", CreateTokenIxsAccounts::ACCOUNTS);
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use create_token::{processor::CreateTokenIxsData, state::Favorites};
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_favorites_layout() {
+        assert_layout!(Favorites, size = 309, {
+            number => 0,
+            color => 8,
+            hobby1 => 58,
+            hobby2 => 108,
+            hobby3 => 158,
+            hobby4 => 208,
+            hobby5 => 258,
+            bump => 308,
+        });
+    }
+
+    #[test]
+    fn test_create_token_ixs_data_layout() {
+        assert_layout!(CreateTokenIxsData, size = 65, {
+            token_decimals => 0,
+            mint_authority => 1,
+            freeze_authority => 33,
+        });
+    }
+}
@@ -0,0 +1,5 @@
+pub const POOL_SEED: &[u8] = b"pool";
+pub const STAKE_SEED: &[u8] = b"stake";
+
+/// Fixed-point scale for `StakePool::acc_reward_per_share`.
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
@@ -0,0 +1,38 @@
+use pinocchio::{
+    account_info::AccountInfo, entrypoint, program_error::ProgramError, pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_log::log;
+
+use crate::processor::{ClaimRewards, InitializePool, Instruction, Stake, Unstake};
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    if program_id != &crate::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    match Instruction::try_from(instruction_data)? {
+        Instruction::InitializePool(data) => {
+            log!("Instruction: InitializePool");
+            InitializePool::try_from((accounts, data))?.handler()
+        }
+        Instruction::Stake(data) => {
+            log!("Instruction: Stake");
+            Stake::try_from((accounts, data))?.handler()
+        }
+        Instruction::Unstake(data) => {
+            log!("Instruction: Unstake");
+            Unstake::try_from((accounts, data))?.handler()
+        }
+        Instruction::ClaimRewards => {
+            log!("Instruction: ClaimRewards");
+            ClaimRewards::try_from(accounts)?.handler()
+        }
+    }
+}
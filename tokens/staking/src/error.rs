@@ -0,0 +1,23 @@
+use pinocchio_helper::program_error;
+
+program_error! {
+    /// Errors returned by the staking program
+    pub enum StakingError {
+        /// Arithmetic overflow while updating balances or rewards
+        Overflow = 6001,
+        /// A token account does not hold the expected mint
+        InvalidMint = 6002,
+        /// The vault is not the pool's stake token account
+        InvalidVault = 6003,
+        /// The stake entry belongs to a different owner or pool
+        InvalidStakeOwner = 6004,
+        /// Unstaking more than the entry holds
+        InsufficientStake = 6005,
+        /// Stake and unstake amounts must be non-zero
+        ZeroAmount = 6006,
+        /// The stake mint has a mint authority and the pool authority is not it
+        NotStakeMintAuthority = 6007,
+        /// The reward mint's mint authority is not the pool PDA
+        InvalidRewardMint = 6008,
+    }
+}
@@ -0,0 +1,12 @@
+#![no_std]
+#![allow(unexpected_cfgs)]
+
+pub mod constants;
+pub mod error;
+pub mod processor;
+pub mod state;
+
+#[cfg(not(feature = "no-entrypoint"))]
+pub mod entrypoint;
+
+pinocchio_pubkey::declare_id!("DwVKKrCnhu41Xg1H67LDyXQxiBqPV16XGzDv2vyhSMmY");
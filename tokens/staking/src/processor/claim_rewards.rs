@@ -0,0 +1,81 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_helper::{
+    checks::{require_associated_token_account, require_key},
+    instruction_accounts,
+    le::U64LE,
+    seeds,
+};
+use pinocchio_log::log;
+
+use crate::{
+    constants::POOL_SEED,
+    processor::{load_pool_mut, load_stake_entry_mut, require_token_mint},
+};
+
+instruction_accounts! {
+    pub struct ClaimRewardsIxsAccounts {
+        [signer] owner,
+        [writable, program_owned] pool,
+        [writable, program_owned] stake_entry,
+        [writable] reward_mint,
+        [writable] owner_reward_token,
+        token_program,
+    }
+}
+
+pub struct ClaimRewards<'info> {
+    pub accounts: ClaimRewardsIxsAccounts<'info>,
+}
+
+impl<'info> TryFrom<&'info [AccountInfo]> for ClaimRewards<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ClaimRewardsIxsAccounts::try_from(accounts)?;
+        require_key(5, accounts.token_program, &pinocchio_token::ID)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ClaimRewards<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let pool = load_pool_mut(self.accounts.pool)?;
+        require_key(3, self.accounts.reward_mint, &pool.reward_mint)?;
+        require_associated_token_account(
+            4,
+            self.accounts.owner_reward_token,
+            self.accounts.owner.key(),
+            &pool.reward_mint,
+            &pinocchio_token::ID,
+        )?;
+        require_token_mint(self.accounts.owner_reward_token, &pool.reward_mint)?;
+        let entry =
+            load_stake_entry_mut(self.accounts.stake_entry, self.accounts.pool, self.accounts.owner)?;
+
+        pool.accrue(Clock::get()?.slot)?;
+        entry.settle(pool.acc_reward_per_share())?;
+
+        let rewards = entry.pending_rewards();
+        if rewards == 0 {
+            log!("No rewards to claim");
+            return Ok(());
+        }
+        entry.pending_rewards = U64LE::ZERO;
+
+        seeds!(seed = [POOL_SEED, &pool.stake_mint], pool.bump);
+
+        pinocchio_token::instructions::MintTo {
+            mint: self.accounts.reward_mint,
+            account: self.accounts.owner_reward_token,
+            mint_authority: self.accounts.pool,
+            amount: rewards,
+        }
+        .invoke_signed(&[Signer::from(&seed)])
+    }
+}
@@ -0,0 +1,135 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_helper::{
+    checks::{require_owner, require_pda},
+    cpi::create_pda_account,
+    instruction_accounts,
+    le::{U128LE, U64LE},
+    seeds,
+};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+use crate::{
+    constants::POOL_SEED,
+    error::StakingError,
+    processor::load_pool_mut,
+    state::StakePool,
+};
+
+instruction_accounts! {
+    pub struct InitializePoolIxsAccounts {
+        [signer, writable] payer,
+        [signer] authority,
+        [writable, empty] pool,
+        stake_mint,
+        reward_mint,
+        vault,
+        system_program,
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct InitializePoolIxsData {
+    #[idl_type("u64")]
+    pub reward_rate: U64LE,
+}
+
+impl InitializePoolIxsData {
+    pub const LEN: usize = core::mem::size_of::<InitializePoolIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for InitializePoolIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct InitializePool<'info> {
+    pub accounts: InitializePoolIxsAccounts<'info>,
+    pub data: InitializePoolIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], InitializePoolIxsData)> for InitializePool<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], InitializePoolIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitializePoolIxsAccounts::try_from(accounts)?;
+        require_owner(3, accounts.stake_mint, &pinocchio_token::ID)?;
+        require_owner(4, accounts.reward_mint, &pinocchio_token::ID)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> InitializePool<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let (pool_pubkey, bump) = pubkey::find_program_address(
+            &[POOL_SEED, self.accounts.stake_mint.key().as_ref()],
+            &crate::ID,
+        );
+        require_pda(2, self.accounts.pool, &pool_pubkey)?;
+
+        // While the stake mint has an authority, only it may open the pool, so
+        // nobody can squat a live mint with their own reward mint and rate.
+        // Fixed-supply mints have no authority left to ask, so their pool is
+        // open to whoever creates it first.
+        {
+            let stake_mint = Mint::from_account_info(self.accounts.stake_mint)?;
+            if let Some(mint_authority) = stake_mint.mint_authority() {
+                if mint_authority != self.accounts.authority.key() {
+                    return Err(StakingError::NotStakeMintAuthority.into());
+                }
+            }
+        }
+
+        {
+            let reward_mint = Mint::from_account_info(self.accounts.reward_mint)?;
+            if reward_mint.mint_authority() != Some(&pool_pubkey) {
+                return Err(StakingError::InvalidRewardMint.into());
+            }
+        }
+
+        {
+            let vault = TokenAccount::from_account_info(self.accounts.vault)?;
+            if vault.mint() != self.accounts.stake_mint.key() || vault.owner() != &pool_pubkey {
+                return Err(StakingError::InvalidVault.into());
+            }
+        }
+
+        seeds!(seed = [POOL_SEED, self.accounts.stake_mint.key()], bump);
+        create_pda_account(
+            self.accounts.payer,
+            self.accounts.pool,
+            StakePool::LEN,
+            &crate::ID,
+            &seed,
+        )?;
+
+        load_pool_mut(self.accounts.pool)?.set_inner(StakePool {
+            authority: *self.accounts.authority.key(),
+            stake_mint: *self.accounts.stake_mint.key(),
+            reward_mint: *self.accounts.reward_mint.key(),
+            vault: *self.accounts.vault.key(),
+            reward_rate: self.data.reward_rate,
+            total_staked: U64LE::ZERO,
+            acc_reward_per_share: U128LE::ZERO,
+            last_update_slot: U64LE::new(Clock::get()?.slot),
+            bump,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+pub mod claim_rewards;
+pub mod initialize_pool;
+pub mod shared;
+pub mod stake;
+pub mod unstake;
+
+pub use claim_rewards::*;
+pub use initialize_pool::*;
+pub use shared::*;
+pub use stake::*;
+pub use unstake::*;
+
+use pinocchio::program_error::ProgramError;
+use shank::ShankInstruction;
+
+#[repr(u8)]
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, writable, signer, name = "payer", desc = "Pays for the pool account")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Pool authority, must be the stake mint's mint authority unless the mint has none"
+    )]
+    #[account(2, writable, name = "pool", desc = "Pool PDA derived from the stake mint")]
+    #[account(3, name = "stake_mint", desc = "Mint of the staked token")]
+    #[account(4, name = "reward_mint", desc = "Reward mint, mint authority must be the pool PDA")]
+    #[account(5, name = "vault", desc = "Stake mint token account owned by the pool PDA")]
+    #[account(6, name = "system_program", desc = "The system program")]
+    InitializePool(InitializePoolIxsData),
+
+    #[account(0, writable, signer, name = "owner", desc = "Staker, pays for the stake entry")]
+    #[account(1, writable, name = "pool", desc = "The staking pool")]
+    #[account(2, writable, name = "stake_entry", desc = "Staker's entry PDA, created on first stake")]
+    #[account(3, writable, name = "owner_token", desc = "Staker's stake mint token account")]
+    #[account(4, writable, name = "vault", desc = "The pool vault")]
+    #[account(5, name = "system_program", desc = "The system program")]
+    #[account(6, name = "token_program", desc = "The token program")]
+    Stake(StakeIxsData),
+
+    #[account(0, signer, name = "owner", desc = "Staker")]
+    #[account(1, writable, name = "pool", desc = "The staking pool")]
+    #[account(2, writable, name = "stake_entry", desc = "Staker's entry PDA")]
+    #[account(3, writable, name = "owner_token", desc = "Staker's stake mint token account")]
+    #[account(4, writable, name = "vault", desc = "The pool vault")]
+    #[account(5, name = "token_program", desc = "The token program")]
+    Unstake(UnstakeIxsData),
+
+    #[account(0, signer, name = "owner", desc = "Staker")]
+    #[account(1, writable, name = "pool", desc = "The staking pool")]
+    #[account(2, writable, name = "stake_entry", desc = "Staker's entry PDA")]
+    #[account(3, writable, name = "reward_mint", desc = "The pool reward mint")]
+    #[account(4, writable, name = "owner_reward_token", desc = "Staker's reward mint token account")]
+    #[account(5, name = "token_program", desc = "The token program")]
+    ClaimRewards,
+}
+
+/// Splits off the discriminator and parses the payload into the variant, so
+/// the arguments Shank reads from each variant are the ones handlers receive.
+impl TryFrom<&[u8]> for Instruction {
+    type Error = ProgramError;
+
+    fn try_from(instruction_data: &[u8]) -> Result<Self, Self::Error> {
+        let (discriminator, data) = instruction_data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match *discriminator {
+            0 => Ok(Instruction::InitializePool(
+                InitializePoolIxsData::try_from(data)?,
+            )),
+            1 => Ok(Instruction::Stake(StakeIxsData::try_from(data)?)),
+            2 => Ok(Instruction::Unstake(UnstakeIxsData::try_from(data)?)),
+            3 => Ok(Instruction::ClaimRewards),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_token::state::TokenAccount;
+
+use crate::{
+    error::StakingError,
+    state::{StakeEntry, StakePool},
+};
+
+/// Borrow the pool account data. The caller must have checked ownership.
+pub fn load_pool_mut(pool: &AccountInfo) -> Result<&mut StakePool, ProgramError> {
+    unsafe {
+        bytemuck::try_from_bytes_mut::<StakePool>(pool.borrow_mut_data_unchecked())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Borrow a stake entry and check it belongs to `pool` and `owner`.
+pub fn load_stake_entry_mut<'a>(
+    stake_entry: &'a AccountInfo,
+    pool: &AccountInfo,
+    owner: &AccountInfo,
+) -> Result<&'a mut StakeEntry, ProgramError> {
+    let entry = unsafe {
+        bytemuck::try_from_bytes_mut::<StakeEntry>(stake_entry.borrow_mut_data_unchecked())
+            .map_err(|_| ProgramError::InvalidAccountData)?
+    };
+
+    if entry.pool.ne(pool.key()) || entry.owner.ne(owner.key()) {
+        return Err(StakingError::InvalidStakeOwner.into());
+    }
+
+    Ok(entry)
+}
+
+/// Check that `token_account` is a token account for `mint`.
+///
+/// The borrow is released before returning so the account can be passed to a CPI.
+pub fn require_token_mint(token_account: &AccountInfo, mint: &Pubkey) -> Result<(), ProgramError> {
+    let token_account = TokenAccount::from_account_info(token_account)?;
+    if token_account.mint() != mint {
+        return Err(StakingError::InvalidMint.into());
+    }
+    Ok(())
+}
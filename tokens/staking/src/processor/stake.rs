@@ -0,0 +1,162 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_helper::{
+    checks::{require_associated_token_account, require_key, require_owner, require_pda},
+    cpi::create_pda_account,
+    instruction_accounts,
+    le::{U128LE, U64LE},
+    seeds,
+};
+
+use crate::{
+    constants::STAKE_SEED,
+    error::StakingError,
+    processor::{load_pool_mut, load_stake_entry_mut, require_token_mint},
+    state::StakeEntry,
+};
+
+instruction_accounts! {
+    pub struct StakeIxsAccounts {
+        [signer, writable] owner,
+        [writable, program_owned] pool,
+        [writable] stake_entry,
+        [writable] owner_token,
+        [writable] vault,
+        system_program,
+        token_program,
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct StakeIxsData {
+    #[idl_type("u64")]
+    pub amount: U64LE,
+}
+
+impl StakeIxsData {
+    pub const LEN: usize = core::mem::size_of::<StakeIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for StakeIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct Stake<'info> {
+    pub accounts: StakeIxsAccounts<'info>,
+    pub data: StakeIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], StakeIxsData)> for Stake<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], StakeIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = StakeIxsAccounts::try_from(accounts)?;
+        require_key(6, accounts.token_program, &pinocchio_token::ID)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> Stake<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let amount = self.data.amount.get();
+        if amount == 0 {
+            return Err(StakingError::ZeroAmount.into());
+        }
+
+        let pool = load_pool_mut(self.accounts.pool)?;
+        require_key(4, self.accounts.vault, &pool.vault)?;
+        require_associated_token_account(
+            3,
+            self.accounts.owner_token,
+            self.accounts.owner.key(),
+            &pool.stake_mint,
+            &pinocchio_token::ID,
+        )?;
+        require_token_mint(self.accounts.owner_token, &pool.stake_mint)?;
+
+        if self.accounts.stake_entry.data_len() == 0 {
+            self.create_stake_entry()?;
+        } else {
+            require_owner(2, self.accounts.stake_entry, &crate::ID)?;
+        }
+        let entry =
+            load_stake_entry_mut(self.accounts.stake_entry, self.accounts.pool, self.accounts.owner)?;
+
+        pool.accrue(Clock::get()?.slot)?;
+        entry.settle(pool.acc_reward_per_share())?;
+
+        pinocchio_token::instructions::Transfer {
+            from: self.accounts.owner_token,
+            to: self.accounts.vault,
+            authority: self.accounts.owner,
+            amount,
+        }
+        .invoke()?;
+
+        let staked = entry.amount().checked_add(amount).ok_or(StakingError::Overflow)?;
+        entry.set_amount(staked, pool.acc_reward_per_share())?;
+        pool.total_staked = pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(StakingError::Overflow)?;
+
+        Ok(())
+    }
+
+    fn create_stake_entry(&self) -> ProgramResult {
+        let (entry_pubkey, bump) = pubkey::find_program_address(
+            &[
+                STAKE_SEED,
+                self.accounts.pool.key().as_ref(),
+                self.accounts.owner.key().as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_pda(2, self.accounts.stake_entry, &entry_pubkey)?;
+
+        seeds!(
+            seed = [STAKE_SEED, self.accounts.pool.key(), self.accounts.owner.key()],
+            bump
+        );
+        create_pda_account(
+            self.accounts.owner,
+            self.accounts.stake_entry,
+            StakeEntry::LEN,
+            &crate::ID,
+            &seed,
+        )?;
+
+        let entry = unsafe {
+            bytemuck::try_from_bytes_mut::<StakeEntry>(
+                self.accounts.stake_entry.borrow_mut_data_unchecked(),
+            )
+            .map_err(|_| ProgramError::InvalidAccountData)?
+        };
+        entry.set_inner(StakeEntry {
+            pool: *self.accounts.pool.key(),
+            owner: *self.accounts.owner.key(),
+            amount: U64LE::ZERO,
+            reward_debt: U128LE::ZERO,
+            pending_rewards: U64LE::ZERO,
+            bump,
+        });
+
+        Ok(())
+    }
+}
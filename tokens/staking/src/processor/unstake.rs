@@ -0,0 +1,115 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_helper::{
+    checks::{require_associated_token_account, require_key},
+    instruction_accounts,
+    le::U64LE,
+    seeds,
+};
+
+use crate::{
+    constants::POOL_SEED,
+    error::StakingError,
+    processor::{load_pool_mut, load_stake_entry_mut, require_token_mint},
+};
+
+instruction_accounts! {
+    pub struct UnstakeIxsAccounts {
+        [signer] owner,
+        [writable, program_owned] pool,
+        [writable, program_owned] stake_entry,
+        [writable] owner_token,
+        [writable] vault,
+        token_program,
+    }
+}
+
+#[derive(shank::ShankType)]
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct UnstakeIxsData {
+    #[idl_type("u64")]
+    pub amount: U64LE,
+}
+
+impl UnstakeIxsData {
+    pub const LEN: usize = core::mem::size_of::<UnstakeIxsData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for UnstakeIxsData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let result = bytemuck::try_from_bytes::<Self>(data)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(*result)
+    }
+}
+
+pub struct Unstake<'info> {
+    pub accounts: UnstakeIxsAccounts<'info>,
+    pub data: UnstakeIxsData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], UnstakeIxsData)> for Unstake<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountInfo], UnstakeIxsData),
+    ) -> Result<Self, Self::Error> {
+        let accounts = UnstakeIxsAccounts::try_from(accounts)?;
+        require_key(5, accounts.token_program, &pinocchio_token::ID)?;
+        Ok(Self { accounts, data })
+    }
+}
+
+impl<'info> Unstake<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let amount = self.data.amount.get();
+        if amount == 0 {
+            return Err(StakingError::ZeroAmount.into());
+        }
+
+        let pool = load_pool_mut(self.accounts.pool)?;
+        require_key(4, self.accounts.vault, &pool.vault)?;
+        require_associated_token_account(
+            3,
+            self.accounts.owner_token,
+            self.accounts.owner.key(),
+            &pool.stake_mint,
+            &pinocchio_token::ID,
+        )?;
+        require_token_mint(self.accounts.owner_token, &pool.stake_mint)?;
+        let entry =
+            load_stake_entry_mut(self.accounts.stake_entry, self.accounts.pool, self.accounts.owner)?;
+
+        let remaining = entry
+            .amount()
+            .checked_sub(amount)
+            .ok_or(StakingError::InsufficientStake)?;
+
+        pool.accrue(Clock::get()?.slot)?;
+        entry.settle(pool.acc_reward_per_share())?;
+        entry.set_amount(remaining, pool.acc_reward_per_share())?;
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(StakingError::Overflow)?;
+
+        seeds!(seed = [POOL_SEED, &pool.stake_mint], pool.bump);
+
+        pinocchio_token::instructions::Transfer {
+            from: self.accounts.vault,
+            to: self.accounts.owner_token,
+            authority: self.accounts.pool,
+            amount,
+        }
+        .invoke_signed(&[Signer::from(&seed)])
+    }
+}
@@ -0,0 +1,5 @@
+pub mod pool;
+pub mod stake_entry;
+
+pub use pool::*;
+pub use stake_entry::*;
@@ -0,0 +1,76 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_helper::le::{U128LE, U64LE};
+use shank::ShankAccount;
+
+use crate::{constants::REWARD_PRECISION, error::StakingError};
+
+/// Staking pool stored at the PDA `[POOL_SEED, stake_mint]`.
+///
+/// Rewards accrue per slot at `reward_rate` and are shared pro rata between
+/// stakers through the `acc_reward_per_share` accumulator.
+#[derive(Clone, Copy, Pod, Zeroable, ShankAccount)]
+#[repr(C)]
+pub struct StakePool {
+    /// Signer that created the pool: the stake mint's mint authority, or the
+    /// first caller for a fixed-supply mint
+    pub authority: Pubkey,
+    pub stake_mint: Pubkey,
+    /// Mint whose mint authority is this pool PDA
+    pub reward_mint: Pubkey,
+    /// Token account holding staked tokens, owned by this pool PDA
+    pub vault: Pubkey,
+    /// Reward tokens minted per slot across all stakers
+    #[idl_type("u64")]
+    pub reward_rate: U64LE,
+    #[idl_type("u64")]
+    pub total_staked: U64LE,
+    /// Rewards per staked token since creation, scaled by `REWARD_PRECISION`
+    #[idl_type("u128")]
+    pub acc_reward_per_share: U128LE,
+    #[idl_type("u64")]
+    pub last_update_slot: U64LE,
+    pub bump: u8,
+}
+
+impl StakePool {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    pub fn set_inner(&mut self, data: Self) -> Self {
+        *self = data;
+        *self
+    }
+
+    pub fn total_staked(&self) -> u64 {
+        self.total_staked.get()
+    }
+
+    pub fn acc_reward_per_share(&self) -> u128 {
+        self.acc_reward_per_share.get()
+    }
+
+    /// Bring the accumulator up to `current_slot`.
+    ///
+    /// Slots during which nothing was staked produce no rewards.
+    pub fn accrue(&mut self, current_slot: u64) -> Result<(), ProgramError> {
+        let last_update_slot = self.last_update_slot.get();
+        if current_slot <= last_update_slot {
+            return Ok(());
+        }
+
+        let total_staked = self.total_staked();
+        if total_staked > 0 {
+            let rewards = ((current_slot - last_update_slot) as u128)
+                .checked_mul(self.reward_rate.get() as u128)
+                .and_then(|rewards| rewards.checked_mul(REWARD_PRECISION))
+                .ok_or(StakingError::Overflow)?;
+            self.acc_reward_per_share = self
+                .acc_reward_per_share
+                .checked_add(rewards / total_staked as u128)
+                .ok_or(StakingError::Overflow)?;
+        }
+
+        self.last_update_slot.set(current_slot);
+        Ok(())
+    }
+}
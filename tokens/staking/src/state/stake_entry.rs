@@ -0,0 +1,73 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_helper::le::{U128LE, U64LE};
+use shank::ShankAccount;
+
+use crate::{constants::REWARD_PRECISION, error::StakingError};
+
+/// A single staker's position, stored at the PDA `[STAKE_SEED, pool, owner]`.
+#[derive(Clone, Copy, Pod, Zeroable, ShankAccount)]
+#[repr(C)]
+pub struct StakeEntry {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    #[idl_type("u64")]
+    pub amount: U64LE,
+    /// `amount * acc_reward_per_share` at the last settlement, unscaled
+    #[idl_type("u128")]
+    pub reward_debt: U128LE,
+    /// Rewards settled but not yet claimed
+    #[idl_type("u64")]
+    pub pending_rewards: U64LE,
+    pub bump: u8,
+}
+
+impl StakeEntry {
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    pub fn set_inner(&mut self, data: Self) -> Self {
+        *self = data;
+        *self
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.get()
+    }
+
+    pub fn pending_rewards(&self) -> u64 {
+        self.pending_rewards.get()
+    }
+
+    /// Move everything earned since the last settlement into `pending_rewards`.
+    ///
+    /// Must run against an accrued pool before `amount` changes.
+    pub fn settle(&mut self, acc_reward_per_share: u128) -> Result<(), ProgramError> {
+        let accumulated = self.accumulated(acc_reward_per_share)?;
+        let earned = accumulated
+            .checked_sub(self.reward_debt.get())
+            .ok_or(StakingError::Overflow)?;
+        let pending = (self.pending_rewards() as u128)
+            .checked_add(earned)
+            .and_then(|pending| u64::try_from(pending).ok())
+            .ok_or(StakingError::Overflow)?;
+
+        self.pending_rewards.set(pending);
+        self.reward_debt.set(accumulated);
+        Ok(())
+    }
+
+    /// Set a new staked amount, resetting the reward debt so the change
+    /// only earns from now on.
+    pub fn set_amount(&mut self, amount: u64, acc_reward_per_share: u128) -> Result<(), ProgramError> {
+        self.amount.set(amount);
+        self.reward_debt.set(self.accumulated(acc_reward_per_share)?);
+        Ok(())
+    }
+
+    fn accumulated(&self, acc_reward_per_share: u128) -> Result<u128, ProgramError> {
+        (self.amount() as u128)
+            .checked_mul(acc_reward_per_share)
+            .map(|scaled| scaled / REWARD_PRECISION)
+            .ok_or_else(|| StakingError::Overflow.into())
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use staking::processor::{
+        ClaimRewardsIxsAccounts, InitializePoolIxsAccounts, StakeIxsAccounts, UnstakeIxsAccounts,
+    };
+    use test_support::idl::assert_accounts_match;
+
+    const IDL: &str = include_str!("../../../idl/staking.json");
+
+    #[test]
+    fn test_initialize_pool_accounts_match_idl() {
+        assert_accounts_match(IDL, "InitializePool", InitializePoolIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_stake_accounts_match_idl() {
+        assert_accounts_match(IDL, "Stake", StakeIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_unstake_accounts_match_idl() {
+        assert_accounts_match(IDL, "Unstake", UnstakeIxsAccounts::ACCOUNTS);
+    }
+
+    #[test]
+    fn test_claim_rewards_accounts_match_idl() {
+        assert_accounts_match(IDL, "ClaimRewards", ClaimRewardsIxsAccounts::ACCOUNTS);
+    }
+}
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use staking::{
+        processor::{InitializePoolIxsData, StakeIxsData, UnstakeIxsData},
+        state::{StakeEntry, StakePool},
+    };
+    use test_support::assert_layout;
+
+    #[test]
+    fn test_stake_pool_layout() {
+        assert_layout!(StakePool, size = 169, {
+            authority => 0,
+            stake_mint => 32,
+            reward_mint => 64,
+            vault => 96,
+            reward_rate => 128,
+            total_staked => 136,
+            acc_reward_per_share => 144,
+            last_update_slot => 160,
+            bump => 168,
+        });
+    }
+
+    #[test]
+    fn test_stake_entry_layout() {
+        assert_layout!(StakeEntry, size = 97, {
+            pool => 0,
+            owner => 32,
+            amount => 64,
+            reward_debt => 72,
+            pending_rewards => 88,
+            bump => 96,
+        });
+    }
+
+    #[test]
+    fn test_initialize_pool_ixs_data_layout() {
+        assert_layout!(InitializePoolIxsData, size = 8, {
+            reward_rate => 0,
+        });
+    }
+
+    #[test]
+    fn test_stake_ixs_data_layout() {
+        assert_layout!(StakeIxsData, size = 8, {
+            amount => 0,
+        });
+    }
+
+    #[test]
+    fn test_unstake_ixs_data_layout() {
+        assert_layout!(UnstakeIxsData, size = 8, {
+            amount => 0,
+        });
+    }
+}
@@ -0,0 +1,686 @@
+#[cfg(test)]
+mod tests {
+    use mollusk_svm::{result::Check, Mollusk};
+    use mollusk_svm_programs_token::token;
+    use pinocchio_helper::{
+        le::{U128LE, U64LE},
+        token::ASSOCIATED_TOKEN_PROGRAM_ID,
+    };
+    use solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+    use staking::{
+        constants::{POOL_SEED, REWARD_PRECISION, STAKE_SEED},
+        processor::{InitializePoolIxsData, StakeIxsData},
+        state::{StakeEntry, StakePool},
+        ID,
+    };
+    use test_support::snapshot;
+
+    pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
+
+    const TOKEN_ACCOUNT_LEN: usize = 165;
+    const MINT_LEN: usize = 82;
+
+    struct Fixture {
+        mollusk: Mollusk,
+        authority: Pubkey,
+        owner: Pubkey,
+        stake_mint: Pubkey,
+        reward_mint: Pubkey,
+        pool: Pubkey,
+        pool_bump: u8,
+        vault: Pubkey,
+        stake_entry: Pubkey,
+        entry_bump: u8,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let mut mollusk = test_support::mollusk(&PROGRAM_ID, "staking");
+            token::add_program(&mut mollusk);
+
+            let owner = Pubkey::new_from_array([0x02; 32]);
+            let stake_mint = Pubkey::new_unique();
+            let reward_mint = Pubkey::new_unique();
+            let (pool, pool_bump) =
+                Pubkey::find_program_address(&[POOL_SEED, stake_mint.as_ref()], &PROGRAM_ID);
+            let (stake_entry, entry_bump) = Pubkey::find_program_address(
+                &[STAKE_SEED, pool.as_ref(), owner.as_ref()],
+                &PROGRAM_ID,
+            );
+
+            Self {
+                mollusk,
+                authority: Pubkey::new_from_array([0x03; 32]),
+                owner,
+                stake_mint,
+                reward_mint,
+                pool,
+                pool_bump,
+                vault: Pubkey::new_unique(),
+                stake_entry,
+                entry_bump,
+            }
+        }
+
+        /// The owner's canonical associated token account for `mint`
+        fn owner_ata(&self, mint: &Pubkey) -> Pubkey {
+            Pubkey::find_program_address(
+                &[self.owner.as_ref(), token::ID.as_ref(), mint.as_ref()],
+                &Pubkey::new_from_array(ASSOCIATED_TOKEN_PROGRAM_ID),
+            )
+            .0
+        }
+
+        fn pool_account(&self, reward_rate: u64, total_staked: u64) -> Account {
+            let pool = StakePool {
+                authority: self.authority.to_bytes(),
+                stake_mint: self.stake_mint.to_bytes(),
+                reward_mint: self.reward_mint.to_bytes(),
+                vault: self.vault.to_bytes(),
+                reward_rate: U64LE::new(reward_rate),
+                total_staked: U64LE::new(total_staked),
+                acc_reward_per_share: U128LE::ZERO,
+                last_update_slot: U64LE::ZERO,
+                bump: self.pool_bump,
+            };
+            self.program_account(bytemuck::bytes_of(&pool))
+        }
+
+        fn stake_entry_account(&self, amount: u64) -> Account {
+            let entry = StakeEntry {
+                pool: self.pool.to_bytes(),
+                owner: self.owner.to_bytes(),
+                amount: U64LE::new(amount),
+                reward_debt: U128LE::ZERO,
+                pending_rewards: U64LE::ZERO,
+                bump: self.entry_bump,
+            };
+            self.program_account(bytemuck::bytes_of(&entry))
+        }
+
+        fn program_account(&self, data: &[u8]) -> Account {
+            Account {
+                lamports: self.mollusk.sysvars.rent.minimum_balance(data.len()),
+                data: data.to_vec(),
+                owner: PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            }
+        }
+
+        fn token_account(&self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+            let mut data = vec![0u8; TOKEN_ACCOUNT_LEN];
+            data[0..32].copy_from_slice(mint.as_ref());
+            data[32..64].copy_from_slice(owner.as_ref());
+            data[64..72].copy_from_slice(&amount.to_le_bytes());
+            // AccountState::Initialized
+            data[108] = 1;
+            self.token_program_account(data)
+        }
+
+        fn mint_account(&self, mint_authority: &Pubkey) -> Account {
+            let mut data = vec![0u8; MINT_LEN];
+            // COption::Some(mint_authority)
+            data[0] = 1;
+            data[4..36].copy_from_slice(mint_authority.as_ref());
+            data[44] = 6;
+            data[45] = 1;
+            self.token_program_account(data)
+        }
+
+        /// `InitializePool` at `reward_rate`, with the pool marked writable
+        /// or not, and the accounts it runs against. The stake mint's
+        /// authority is `stake_mint_authority` and the reward mint's is
+        /// `reward_mint_authority`; a valid setup passes the fixture
+        /// authority and the pool PDA.
+        fn initialize_pool(
+            &self,
+            pool_writable: bool,
+            stake_mint_authority: &Pubkey,
+            reward_mint_authority: &Pubkey,
+        ) -> (Instruction, Vec<(Pubkey, Account)>) {
+            let (system_program, system_account) =
+                mollusk_svm::program::keyed_account_for_system_program();
+            let ix_data = InitializePoolIxsData {
+                reward_rate: U64LE::new(10),
+            };
+            let pool_meta = if pool_writable {
+                AccountMeta::new(self.pool, false)
+            } else {
+                AccountMeta::new_readonly(self.pool, false)
+            };
+
+            let instruction = Instruction::new_with_bytes(
+                PROGRAM_ID,
+                &[vec![0], bytemuck::bytes_of(&ix_data).to_vec()].concat(),
+                vec![
+                    AccountMeta::new(self.owner, true),
+                    AccountMeta::new_readonly(self.authority, true),
+                    pool_meta,
+                    AccountMeta::new_readonly(self.stake_mint, false),
+                    AccountMeta::new_readonly(self.reward_mint, false),
+                    AccountMeta::new_readonly(self.vault, false),
+                    AccountMeta::new_readonly(system_program, false),
+                ],
+            );
+            let accounts = vec![
+                (
+                    self.owner,
+                    Account::new(LAMPORTS_PER_SOL, 0, &system_program),
+                ),
+                (self.authority, Account::new(0, 0, &system_program)),
+                (self.pool, Account::new(0, 0, &system_program)),
+                (self.stake_mint, self.mint_account(stake_mint_authority)),
+                (self.reward_mint, self.mint_account(reward_mint_authority)),
+                (
+                    self.vault,
+                    self.token_account(&self.stake_mint, &self.pool, 0),
+                ),
+                (system_program, system_account),
+            ];
+            (instruction, accounts)
+        }
+
+        fn token_program_account(&self, data: Vec<u8>) -> Account {
+            Account {
+                lamports: self.mollusk.sysvars.rent.minimum_balance(data.len()),
+                data,
+                owner: token::ID,
+                executable: false,
+                rent_epoch: 0,
+            }
+        }
+    }
+
+    fn token_amount(account: &Account) -> u64 {
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_initialize_pool() {
+        let mut fixture = Fixture::new();
+        fixture.mollusk.warp_to_slot(7);
+        let (instruction, accounts) =
+            fixture.initialize_pool(true, &fixture.authority, &fixture.pool);
+
+        let result = fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[
+                Check::success(),
+                Check::account(&fixture.pool)
+                    .owner(&PROGRAM_ID)
+                    .space(StakePool::LEN)
+                    .build(),
+            ],
+        );
+
+        let pool = result.get_account(&fixture.pool).unwrap();
+        let pool = bytemuck::from_bytes::<StakePool>(&pool.data);
+        assert_eq!(pool.authority, fixture.authority.to_bytes());
+        assert_eq!(pool.stake_mint, fixture.stake_mint.to_bytes());
+        assert_eq!(pool.reward_mint, fixture.reward_mint.to_bytes());
+        assert_eq!(pool.vault, fixture.vault.to_bytes());
+        assert_eq!(pool.reward_rate.get(), 10);
+        assert_eq!(pool.total_staked(), 0);
+        assert_eq!(pool.last_update_slot.get(), 7);
+        assert_eq!(pool.bump, fixture.pool_bump);
+    }
+
+    #[test]
+    fn test_initialize_pool_requires_stake_mint_authority() {
+        let fixture = Fixture::new();
+        // Someone else signs for a mint they don't control
+        let (instruction, accounts) =
+            fixture.initialize_pool(true, &Pubkey::new_unique(), &fixture.pool);
+
+        fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::Custom(6007))],
+        );
+    }
+
+    #[test]
+    fn test_initialize_pool_with_fixed_supply_stake_mint() {
+        let fixture = Fixture::new();
+        let (instruction, mut accounts) =
+            fixture.initialize_pool(true, &fixture.authority, &fixture.pool);
+        // COption::None: nobody can mint more, so there is no authority to ask
+        let (_, stake_mint) = accounts
+            .iter_mut()
+            .find(|(key, _)| *key == fixture.stake_mint)
+            .unwrap();
+        stake_mint.data[..36].fill(0);
+
+        let result = fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::success()],
+        );
+
+        let pool = result.get_account(&fixture.pool).unwrap();
+        let pool = bytemuck::from_bytes::<StakePool>(&pool.data);
+        assert_eq!(pool.authority, fixture.authority.to_bytes());
+    }
+
+    #[test]
+    fn test_initialize_pool_rejects_foreign_reward_mint() {
+        let fixture = Fixture::new();
+        let (instruction, accounts) =
+            fixture.initialize_pool(true, &fixture.authority, &fixture.authority);
+
+        fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::Custom(6008))],
+        );
+    }
+
+    #[test]
+    fn test_initialize_pool_requires_writable_pool() {
+        let fixture = Fixture::new();
+        let (instruction, accounts) =
+            fixture.initialize_pool(false, &fixture.authority, &fixture.pool);
+
+        fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &accounts,
+            &[Check::err(ProgramError::InvalidAccountData)],
+        );
+    }
+
+    #[test]
+    fn test_stake_creates_entry_and_moves_tokens() {
+        let fixture = Fixture::new();
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = token::keyed_account();
+        let owner_token = fixture.owner_ata(&fixture.stake_mint);
+
+        let ix_data = StakeIxsData {
+            amount: U64LE::new(400),
+        };
+        let data = [vec![1], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    fixture.owner,
+                    Account::new(LAMPORTS_PER_SOL, 0, &system_program),
+                ),
+                (fixture.pool, fixture.pool_account(10, 0)),
+                (fixture.stake_entry, Account::new(0, 0, &system_program)),
+                (
+                    owner_token,
+                    fixture.token_account(&fixture.stake_mint, &fixture.owner, 1_000),
+                ),
+                (
+                    fixture.vault,
+                    fixture.token_account(&fixture.stake_mint, &fixture.pool, 0),
+                ),
+                (system_program, system_account),
+                (token_program, token_program_account),
+            ],
+            &[
+                Check::success(),
+                Check::account(&fixture.stake_entry)
+                    .owner(&PROGRAM_ID)
+                    .build(),
+            ],
+        );
+
+        assert_eq!(token_amount(result.get_account(&owner_token).unwrap()), 600);
+        assert_eq!(token_amount(result.get_account(&fixture.vault).unwrap()), 400);
+
+        let entry = result.get_account(&fixture.stake_entry).unwrap();
+        let entry = bytemuck::from_bytes::<StakeEntry>(&entry.data);
+        assert_eq!(entry.amount(), 400);
+        assert_eq!(entry.owner, fixture.owner.to_bytes());
+
+        let pool = result.get_account(&fixture.pool).unwrap();
+        assert_eq!(bytemuck::from_bytes::<StakePool>(&pool.data).total_staked(), 400);
+    }
+
+    #[test]
+    fn test_stake_into_pool_from_snapshot() {
+        let fixture = Fixture::new();
+        let (initialize, accounts) =
+            fixture.initialize_pool(true, &fixture.authority, &fixture.pool);
+        let initialized = fixture.mollusk.process_and_validate_instruction(
+            &initialize,
+            &accounts,
+            &[Check::success()],
+        );
+
+        // Save the freshly initialized pool and start the next step from the file
+        let path = snapshot::temp_path("staking_initialized_pool");
+        snapshot::save_to(&path, &initialized.resulting_accounts).unwrap();
+        let saved = snapshot::load_from(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(saved, initialized.resulting_accounts);
+
+        let saved_account = |key: &Pubkey| {
+            saved
+                .iter()
+                .find(|(pubkey, _)| pubkey == key)
+                .map(|(_, account)| account.clone())
+                .unwrap()
+        };
+
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = token::keyed_account();
+        let owner_token = fixture.owner_ata(&fixture.stake_mint);
+
+        let ix_data = StakeIxsData {
+            amount: U64LE::new(400),
+        };
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![1], bytemuck::bytes_of(&ix_data).to_vec()].concat(),
+            vec![
+                AccountMeta::new(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (fixture.owner, saved_account(&fixture.owner)),
+                (fixture.pool, saved_account(&fixture.pool)),
+                (fixture.stake_entry, Account::new(0, 0, &system_program)),
+                (
+                    owner_token,
+                    fixture.token_account(&fixture.stake_mint, &fixture.owner, 1_000),
+                ),
+                (fixture.vault, saved_account(&fixture.vault)),
+                (system_program, system_account),
+                (token_program, token_program_account),
+            ],
+            &[Check::success()],
+        );
+
+        assert_eq!(
+            token_amount(result.get_account(&fixture.vault).unwrap()),
+            400
+        );
+        let pool = result.get_account(&fixture.pool).unwrap();
+        assert_eq!(
+            bytemuck::from_bytes::<StakePool>(&pool.data).total_staked(),
+            400
+        );
+    }
+
+    #[test]
+    fn test_claim_rewards_accrues_per_slot() {
+        let mut fixture = Fixture::new();
+        let (token_program, token_program_account) = token::keyed_account();
+        let owner_reward_token = fixture.owner_ata(&fixture.reward_mint);
+
+        // Sole staker of 1_000 tokens at 10 reward tokens per slot for 50 slots.
+        fixture.mollusk.warp_to_slot(50);
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[3],
+            vec![
+                AccountMeta::new_readonly(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(fixture.reward_mint, false),
+                AccountMeta::new(owner_reward_token, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        let result = fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (fixture.owner, Account::new(LAMPORTS_PER_SOL, 0, &Pubkey::default())),
+                (fixture.pool, fixture.pool_account(10, 1_000)),
+                (fixture.stake_entry, fixture.stake_entry_account(1_000)),
+                (fixture.reward_mint, fixture.mint_account(&fixture.pool)),
+                (
+                    owner_reward_token,
+                    fixture.token_account(&fixture.reward_mint, &fixture.owner, 0),
+                ),
+                (token_program, token_program_account),
+            ],
+            &[Check::success()],
+        );
+
+        assert_eq!(
+            token_amount(result.get_account(&owner_reward_token).unwrap()),
+            500
+        );
+
+        let pool = result.get_account(&fixture.pool).unwrap();
+        let pool = bytemuck::from_bytes::<StakePool>(&pool.data);
+        assert_eq!(pool.acc_reward_per_share(), 500 * REWARD_PRECISION / 1_000);
+        assert_eq!(pool.last_update_slot.get(), 50);
+
+        let entry = result.get_account(&fixture.stake_entry).unwrap();
+        assert_eq!(bytemuck::from_bytes::<StakeEntry>(&entry.data).pending_rewards(), 0);
+    }
+
+    #[test]
+    fn test_unstake_more_than_staked_fails() {
+        let fixture = Fixture::new();
+        let (token_program, token_program_account) = token::keyed_account();
+        let owner_token = fixture.owner_ata(&fixture.stake_mint);
+
+        let data = [vec![2], 101u64.to_le_bytes().to_vec()].concat();
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new_readonly(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (fixture.owner, Account::new(LAMPORTS_PER_SOL, 0, &Pubkey::default())),
+                (fixture.pool, fixture.pool_account(10, 100)),
+                (fixture.stake_entry, fixture.stake_entry_account(100)),
+                (
+                    owner_token,
+                    fixture.token_account(&fixture.stake_mint, &fixture.owner, 0),
+                ),
+                (
+                    fixture.vault,
+                    fixture.token_account(&fixture.stake_mint, &fixture.pool, 100),
+                ),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::Custom(6005))],
+        );
+    }
+
+    #[test]
+    fn test_stake_rejects_non_canonical_token_account() {
+        let fixture = Fixture::new();
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = token::keyed_account();
+        // Right owner and mint, but not at the associated token address
+        let owner_token = Pubkey::new_unique();
+
+        let ix_data = StakeIxsData {
+            amount: U64LE::new(400),
+        };
+        let data = [vec![1], bytemuck::bytes_of(&ix_data).to_vec()].concat();
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data,
+            vec![
+                AccountMeta::new(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        fixture.mollusk.process_and_validate_instruction(
+            &instruction,
+            &[
+                (
+                    fixture.owner,
+                    Account::new(LAMPORTS_PER_SOL, 0, &system_program),
+                ),
+                (fixture.pool, fixture.pool_account(10, 0)),
+                (fixture.stake_entry, Account::new(0, 0, &system_program)),
+                (
+                    owner_token,
+                    fixture.token_account(&fixture.stake_mint, &fixture.owner, 1_000),
+                ),
+                (
+                    fixture.vault,
+                    fixture.token_account(&fixture.stake_mint, &fixture.pool, 0),
+                ),
+                (system_program, system_account),
+                (token_program, token_program_account),
+            ],
+            &[Check::err(ProgramError::InvalidSeeds)],
+        );
+    }
+
+    #[test]
+    fn test_claim_stake_unstake_chain() {
+        let mut fixture = Fixture::new();
+        let (system_program, system_account) =
+            mollusk_svm::program::keyed_account_for_system_program();
+        let (token_program, token_program_account) = token::keyed_account();
+        let owner_token = fixture.owner_ata(&fixture.stake_mint);
+        let owner_reward_token = fixture.owner_ata(&fixture.reward_mint);
+
+        // Sole staker of 1_000 tokens at 10 reward tokens per slot for 50 slots.
+        fixture.mollusk.warp_to_slot(50);
+
+        let claim = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[3],
+            vec![
+                AccountMeta::new_readonly(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(fixture.reward_mint, false),
+                AccountMeta::new(owner_reward_token, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+        let stake_data = StakeIxsData {
+            amount: U64LE::new(400),
+        };
+        let stake = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![1], bytemuck::bytes_of(&stake_data).to_vec()].concat(),
+            vec![
+                AccountMeta::new(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+        let unstake = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &[vec![2], 1_400u64.to_le_bytes().to_vec()].concat(),
+            vec![
+                AccountMeta::new_readonly(fixture.owner, true),
+                AccountMeta::new(fixture.pool, false),
+                AccountMeta::new(fixture.stake_entry, false),
+                AccountMeta::new(owner_token, false),
+                AccountMeta::new(fixture.vault, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+        );
+
+        // Token balances and the pool/entry state carry over between steps
+        let result = fixture.mollusk.process_and_validate_instruction_chain(
+            &[
+                (&claim, &[Check::success()]),
+                (&stake, &[Check::success()]),
+                (&unstake, &[Check::success()]),
+            ],
+            &[
+                (
+                    fixture.owner,
+                    Account::new(LAMPORTS_PER_SOL, 0, &system_program),
+                ),
+                (fixture.pool, fixture.pool_account(10, 1_000)),
+                (fixture.stake_entry, fixture.stake_entry_account(1_000)),
+                (fixture.reward_mint, fixture.mint_account(&fixture.pool)),
+                (
+                    owner_reward_token,
+                    fixture.token_account(&fixture.reward_mint, &fixture.owner, 0),
+                ),
+                (
+                    owner_token,
+                    fixture.token_account(&fixture.stake_mint, &fixture.owner, 400),
+                ),
+                (
+                    fixture.vault,
+                    fixture.token_account(&fixture.stake_mint, &fixture.pool, 1_000),
+                ),
+                (system_program, system_account),
+                (token_program, token_program_account),
+            ],
+        );
+
+        assert_eq!(
+            token_amount(result.get_account(&owner_reward_token).unwrap()),
+            500
+        );
+        assert_eq!(token_amount(result.get_account(&owner_token).unwrap()), 1_400);
+        assert_eq!(token_amount(result.get_account(&fixture.vault).unwrap()), 0);
+
+        let entry = result.get_account(&fixture.stake_entry).unwrap();
+        let entry = bytemuck::from_bytes::<StakeEntry>(&entry.data);
+        assert_eq!(entry.amount(), 0);
+        assert_eq!(entry.pending_rewards(), 0);
+
+        let pool = result.get_account(&fixture.pool).unwrap();
+        assert_eq!(bytemuck::from_bytes::<StakePool>(&pool.data).total_staked(), 0);
+    }
+}
@@ -0,0 +1,9 @@
+// Test file for staking
+// Add your Solana Kite tests here
+
+import { expect, test } from 'bun:test';
+
+test('staking:basic', async () => {
+  // Basic test that always passes - replace with actual tests
+  expect(true).toBe(true);
+});